@@ -0,0 +1,413 @@
+//! Derive macros for the `spaces` crate.
+//!
+//! `#[derive(Space)]` generates a companion space type for a struct or enum,
+//! named by appending `Space` to the type's name. The companion is generic
+//! over one space per field, so any type implementing `spaces::Space` with
+//! the matching `Value` may be used for each field.
+//!
+//! ```
+//! # extern crate spaces;
+//! # extern crate spaces_derive;
+//! use spaces::{Space as _, intervals::Interval};
+//! use spaces_derive::Space;
+//!
+//! #[derive(Clone, Debug, PartialEq, Space)]
+//! struct Observation {
+//!     position: f64,
+//!     lives: u8,
+//! }
+//!
+//! let space = ObservationSpace {
+//!     position: Interval::closed_unchecked(-1.0, 1.0),
+//!     lives: Interval::closed_unchecked(0, 3),
+//! };
+//!
+//! assert!(space.contains(&Observation { position: 0.5, lives: 2 }));
+//! assert!(!space.contains(&Observation { position: 1.5, lives: 2 }));
+//! ```
+//!
+//! For enums, the companion space has one field per variant, named in
+//! `snake_case`: unit variants are included or excluded by a `bool`,
+//! single-field variants carry an `Option` of the field's space, and all
+//! other variants an `Option` of a tuple of spaces, in declaration order.
+//!
+//! ```
+//! # extern crate spaces;
+//! # extern crate spaces_derive;
+//! use spaces::{Space as _, FiniteSpace, IterableSpace, intervals::Interval};
+//! use spaces_derive::Space;
+//!
+//! #[derive(Clone, Debug, PartialEq, Space)]
+//! enum Action {
+//!     Noop,
+//!     Turn(i32),
+//!     Move { dx: i32, dy: i32 },
+//! }
+//!
+//! let space = ActionSpace {
+//!     noop: true,
+//!     turn: Some(Interval::closed_unchecked(-1, 1)),
+//!     r#move: Some((Interval::closed_unchecked(0, 1), Interval::degenerate(0))),
+//! };
+//!
+//! assert_eq!(space.cardinality(), 6);
+//! assert!(space.contains(&Action::Turn(1)));
+//! assert!(!space.contains(&Action::Move { dx: 0, dy: 1 }));
+//! assert_eq!(space.elements().collect::<Vec<_>>(), vec![
+//!     Action::Noop,
+//!     Action::Turn(-1), Action::Turn(0), Action::Turn(1),
+//!     Action::Move { dx: 0, dy: 0 }, Action::Move { dx: 1, dy: 0 },
+//! ]);
+//! ```
+//!
+//! __Note__: `IterableSpace` collects the cartesian product of the field
+//! spaces eagerly, and therefore requires each field type to be `Clone`.
+extern crate proc_macro;
+
+use proc_macro2::{Span, TokenStream};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DataEnum, DataStruct, DeriveInput, Fields, Ident,
+    Index, Member, Type,
+};
+
+/// Derive a companion space type for a struct or enum; see the crate docs.
+#[proc_macro_derive(Space)]
+pub fn derive_space(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    let output = if !input.generics.params.is_empty() {
+        Err(syn::Error::new(
+            input.generics.span(),
+            "#[derive(Space)] does not support generic types",
+        ))
+    } else {
+        match input.data {
+            Data::Struct(ref data) => Ok(derive_struct(&input, data)),
+            Data::Enum(ref data) => Ok(derive_enum(&input, data)),
+            Data::Union(ref data) => Err(syn::Error::new(
+                data.union_token.span,
+                "#[derive(Space)] does not support unions",
+            )),
+        }
+    };
+
+    output.unwrap_or_else(|err| err.to_compile_error()).into()
+}
+
+///////////////////////////////////////////////////////////////////
+// Helpers
+///////////////////////////////////////////////////////////////////
+/// A single field of the value type, paired with its space parameter.
+struct Field<'a> {
+    member: Member,
+    ty: &'a Type,
+    param: Ident,
+    binding: Ident,
+}
+
+fn fields<'a>(fields: &'a Fields, prefix: &str) -> Vec<Field<'a>> {
+    fields
+        .iter()
+        .enumerate()
+        .map(|(i, f)| {
+            let (member, name) = match f.ident {
+                Some(ref ident) => (Member::Named(ident.clone()), camel_case(&ident.to_string())),
+                None if fields.len() == 1 && !prefix.is_empty() => (Member::Unnamed(Index::from(i)), String::new()),
+                None if prefix.is_empty() => (Member::Unnamed(Index::from(i)), format!("Field{}", i)),
+                None => (Member::Unnamed(Index::from(i)), i.to_string()),
+            };
+
+            Field {
+                member,
+                ty: &f.ty,
+                param: format_ident!("{}{}Space", prefix, name),
+                binding: format_ident!("__f{}", i),
+            }
+        })
+        .collect()
+}
+
+fn camel_case(name: &str) -> String {
+    name.trim_start_matches("r#")
+        .split('_')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let mut chars = s.chars();
+
+            chars.next().map_or_else(String::new, |c| c.to_uppercase().chain(chars).collect())
+        })
+        .collect()
+}
+
+fn snake_case(name: &str) -> Ident {
+    let mut out = String::new();
+
+    for (i, c) in name.chars().enumerate() {
+        if c.is_uppercase() {
+            if i > 0 {
+                out.push('_');
+            }
+
+            out.extend(c.to_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+
+    // Variant names such as `Move` or `Type` map onto keywords:
+    syn::parse_str::<Ident>(&out).unwrap_or_else(|_| Ident::new_raw(&out, Span::call_site()))
+}
+
+/// Construct the value `path { .. }` or `path(..)` from the bound fields.
+fn construct(path: TokenStream, fields: &Fields, bindings: &[TokenStream]) -> TokenStream {
+    match fields {
+        Fields::Named(named) => {
+            let names = named.named.iter().map(|f| f.ident.as_ref().unwrap());
+
+            quote!(#path { #(#names: #bindings),* })
+        },
+        Fields::Unnamed(_) => quote!(#path(#(#bindings),*)),
+        Fields::Unit => path,
+    }
+}
+
+/// Nest `body` inside one `for` loop over the elements of each field space.
+fn product_loops(spaces: &[TokenStream], bindings: &[Ident], body: TokenStream) -> TokenStream {
+    spaces.iter().zip(bindings.iter()).rev().fold(body, |body, (space, binding)| {
+        quote! {
+            for #binding in ::spaces::IterableSpace::elements(#space) {
+                #body
+            }
+        }
+    })
+}
+
+///////////////////////////////////////////////////////////////////
+// Structs
+///////////////////////////////////////////////////////////////////
+fn derive_struct(input: &DeriveInput, data: &DataStruct) -> TokenStream {
+    let vis = &input.vis;
+    let name = &input.ident;
+    let space_name = format_ident!("{}Space", name);
+    let doc = format!("Space of `{}` values, generated by `#[derive(Space)]`.", name);
+
+    let fields = fields(&data.fields, "");
+    let params: Vec<_> = fields.iter().map(|f| &f.param).collect();
+    let members: Vec<_> = fields.iter().map(|f| &f.member).collect();
+    let types: Vec<_> = fields.iter().map(|f| f.ty).collect();
+    let bindings: Vec<_> = fields.iter().map(|f| &f.binding).collect();
+
+    let definition = match data.fields {
+        Fields::Named(_) => quote! {
+            #vis struct #space_name<#(#params),*> { #(pub #members: #params),* }
+        },
+        Fields::Unnamed(_) => quote! {
+            #vis struct #space_name<#(#params),*>(#(pub #params),*);
+        },
+        Fields::Unit => quote! {
+            #vis struct #space_name;
+        },
+    };
+
+    let spaces: Vec<_> = members.iter().map(|m| quote!(&self.#m)).collect();
+    let cloned: Vec<_> = bindings.iter().map(|b| quote!(::std::clone::Clone::clone(&#b))).collect();
+    let push = {
+        let value = construct(quote!(#name), &data.fields, &cloned);
+
+        quote!(elements.push(#value);)
+    };
+    let loops = product_loops(&spaces, &fields.iter().map(|f| f.binding.clone()).collect::<Vec<_>>(), push);
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #definition
+
+        impl<#(#params),*> ::spaces::Space for #space_name<#(#params),*>
+        where
+            #(#params: ::spaces::Space<Value = #types>),*
+        {
+            type Value = #name;
+
+            fn is_empty(&self) -> bool {
+                false #(|| ::spaces::Space::is_empty(&self.#members))*
+            }
+
+            fn contains(&self, val: &#name) -> bool {
+                true #(&& ::spaces::Space::contains(&self.#members, &val.#members))*
+            }
+        }
+
+        impl<#(#params),*> ::spaces::FiniteSpace for #space_name<#(#params),*>
+        where
+            #(#params: ::spaces::FiniteSpace<Value = #types>),*
+        {
+            fn cardinality(&self) -> usize {
+                1usize #(* ::spaces::FiniteSpace::cardinality(&self.#members))*
+            }
+        }
+
+        impl<#(#params),*> ::spaces::IterableSpace for #space_name<#(#params),*>
+        where
+            #(#params: ::spaces::IterableSpace<Value = #types>, #types: ::std::clone::Clone),*
+        {
+            type ElemIter = ::std::vec::IntoIter<#name>;
+
+            #[allow(clippy::redundant_clone)]
+            fn elements(&self) -> Self::ElemIter {
+                let mut elements = ::std::vec::Vec::new();
+
+                #loops
+
+                elements.into_iter()
+            }
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////
+// Enums
+///////////////////////////////////////////////////////////////////
+fn derive_enum(input: &DeriveInput, data: &DataEnum) -> TokenStream {
+    let vis = &input.vis;
+    let name = &input.ident;
+    let space_name = format_ident!("{}Space", name);
+    let doc = format!("Space of `{}` values, generated by `#[derive(Space)]`.", name);
+
+    let mut params = vec![];
+    let mut types = vec![];
+    let mut definitions = vec![];
+    let mut is_empty = vec![];
+    let mut contains = vec![];
+    let mut cardinality = vec![];
+    let mut elements = vec![];
+
+    for variant in data.variants.iter() {
+        let ident = &variant.ident;
+        let field = snake_case(&ident.to_string());
+        let fields = fields(&variant.fields, &ident.to_string());
+
+        let bindings: Vec<_> = fields.iter().map(|f| f.binding.clone()).collect();
+        let bound: Vec<_> = bindings.iter().map(|b| quote!(#b)).collect();
+        let cloned: Vec<_> = bindings.iter().map(|b| quote!(::std::clone::Clone::clone(&#b))).collect();
+        let pattern = construct(quote!(#name::#ident), &variant.fields, &bound);
+        let value = construct(quote!(#name::#ident), &variant.fields, &cloned);
+
+        params.extend(fields.iter().map(|f| f.param.clone()));
+        types.extend(fields.iter().map(|f| f.ty));
+
+        match fields.len() {
+            0 => {
+                definitions.push(quote!(pub #field: bool));
+                is_empty.push(quote!(!self.#field));
+                contains.push(quote!(#pattern => self.#field));
+                cardinality.push(quote!(if self.#field { 1 } else { 0 }));
+                elements.push(quote!(if self.#field { elements.push(#value); }));
+            },
+            1 => {
+                let param = &fields[0].param;
+                let binding = &fields[0].binding;
+                let spaces = [quote!(space)];
+
+                definitions.push(quote!(pub #field: ::std::option::Option<#param>));
+                is_empty.push(quote! {
+                    self.#field.as_ref().map_or(true, |space| ::spaces::Space::is_empty(space))
+                });
+                contains.push(quote! {
+                    #pattern => self.#field.as_ref().map_or(false, |space| {
+                        ::spaces::Space::contains(space, #binding)
+                    })
+                });
+                cardinality.push(quote! {
+                    self.#field.as_ref().map_or(0, |space| ::spaces::FiniteSpace::cardinality(space))
+                });
+
+                let loops = product_loops(&spaces, &bindings, quote!(elements.push(#value);));
+
+                elements.push(quote! {
+                    if let ::std::option::Option::Some(ref space) = self.#field { #loops }
+                });
+            },
+            _ => {
+                let ps: Vec<_> = fields.iter().map(|f| &f.param).collect();
+                let ss: Vec<_> = (0..fields.len()).map(|i| format_ident!("__s{}", i)).collect();
+                let spaces: Vec<_> = ss.iter().map(|s| quote!(#s)).collect();
+                let loops = product_loops(&spaces, &bindings, quote!(elements.push(#value);));
+
+                definitions.push(quote!(pub #field: ::std::option::Option<(#(#ps),*)>));
+                is_empty.push(quote! {
+                    self.#field.as_ref().map_or(true, |(#(#ss),*)| {
+                        false #(|| ::spaces::Space::is_empty(#ss))*
+                    })
+                });
+                contains.push(quote! {
+                    #pattern => self.#field.as_ref().map_or(false, |(#(#ss),*)| {
+                        true #(&& ::spaces::Space::contains(#ss, #bindings))*
+                    })
+                });
+                cardinality.push(quote! {
+                    self.#field.as_ref().map_or(0, |(#(#ss),*)| {
+                        1usize #(* ::spaces::FiniteSpace::cardinality(#ss))*
+                    })
+                });
+                elements.push(quote! {
+                    if let ::std::option::Option::Some((#(ref #ss),*)) = self.#field { #loops }
+                });
+            },
+        }
+    }
+
+    // Empty enums have no values to match on:
+    let contains = if contains.is_empty() {
+        quote!(match *val {})
+    } else {
+        quote!(match val { #(#contains),* })
+    };
+
+    quote! {
+        #[doc = #doc]
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #vis struct #space_name<#(#params),*> { #(#definitions),* }
+
+        impl<#(#params),*> ::spaces::Space for #space_name<#(#params),*>
+        where
+            #(#params: ::spaces::Space<Value = #types>),*
+        {
+            type Value = #name;
+
+            fn is_empty(&self) -> bool {
+                true #(&& #is_empty)*
+            }
+
+            fn contains(&self, val: &#name) -> bool {
+                #contains
+            }
+        }
+
+        impl<#(#params),*> ::spaces::FiniteSpace for #space_name<#(#params),*>
+        where
+            #(#params: ::spaces::FiniteSpace<Value = #types>),*
+        {
+            fn cardinality(&self) -> usize {
+                0usize #(+ #cardinality)*
+            }
+        }
+
+        impl<#(#params),*> ::spaces::IterableSpace for #space_name<#(#params),*>
+        where
+            #(#params: ::spaces::IterableSpace<Value = #types>, #types: ::std::clone::Clone),*
+        {
+            type ElemIter = ::std::vec::IntoIter<#name>;
+
+            #[allow(unused_mut, clippy::redundant_clone)]
+            fn elements(&self) -> Self::ElemIter {
+                let mut elements = ::std::vec::Vec::new();
+
+                #(#elements)*
+
+                elements.into_iter()
+            }
+        }
+    }
+}
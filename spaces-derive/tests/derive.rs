@@ -0,0 +1,119 @@
+extern crate spaces;
+extern crate spaces_derive;
+
+use spaces::{intervals::Interval, FiniteSpace, IterableSpace, Space as _};
+use spaces_derive::Space;
+
+#[derive(Clone, Debug, PartialEq, Space)]
+struct Named {
+    position: f64,
+    lives: u8,
+}
+
+#[derive(Clone, Debug, PartialEq, Space)]
+struct Unnamed(i32, u8);
+
+#[derive(Clone, Debug, PartialEq, Space)]
+struct Unit;
+
+#[derive(Clone, Debug, PartialEq, Space)]
+enum Action {
+    Noop,
+    Turn(i32),
+    Move { dx: i32, dy: i32 },
+    Type(i32, u8),
+}
+
+#[derive(Clone, Debug, PartialEq, Space)]
+enum Never {}
+
+type Closed<V> = spaces::intervals::Closed<V>;
+
+type ClosedActionSpace = ActionSpace<Closed<i32>, Closed<i32>, Closed<i32>, Closed<i32>, Closed<u8>>;
+
+fn action_space(noop: bool, turn: bool, r#move: bool) -> ClosedActionSpace {
+    ActionSpace {
+        noop,
+        turn: Some(Interval::closed_unchecked(-1, 1)).filter(|_| turn),
+        r#move: Some((Interval::closed_unchecked(0, 1), Interval::closed_unchecked(0, 2)))
+            .filter(|_| r#move),
+        r#type: None,
+    }
+}
+
+#[test]
+fn test_named_struct() {
+    let space = NamedSpace {
+        position: Interval::closed_unchecked(-1.0, 1.0),
+        lives: Interval::closed_unchecked(0, 3),
+    };
+
+    assert!(!space.is_empty());
+    assert!(space.contains(&Named { position: 0.5, lives: 3 }));
+    assert!(!space.contains(&Named { position: 0.5, lives: 4 }));
+    assert!(!space.contains(&Named { position: -1.5, lives: 0 }));
+
+    let space = NamedSpace {
+        position: Interval::open_unchecked(0.0, 0.0),
+        lives: Interval::closed_unchecked(0, 3),
+    };
+
+    assert!(space.is_empty());
+}
+
+#[test]
+fn test_unnamed_struct() {
+    let space = UnnamedSpace(Interval::closed_unchecked(0, 2), Interval::closed_unchecked(0, 1));
+
+    assert!(space.contains(&Unnamed(1, 1)));
+    assert!(!space.contains(&Unnamed(3, 1)));
+    assert_eq!(space.cardinality(), 6);
+    assert_eq!(space.elements().collect::<Vec<_>>(), vec![
+        Unnamed(0, 0),
+        Unnamed(0, 1),
+        Unnamed(1, 0),
+        Unnamed(1, 1),
+        Unnamed(2, 0),
+        Unnamed(2, 1),
+    ]);
+}
+
+#[test]
+fn test_unit_struct() {
+    assert!(!UnitSpace.is_empty());
+    assert!(UnitSpace.contains(&Unit));
+    assert_eq!(UnitSpace.cardinality(), 1);
+    assert_eq!(UnitSpace.elements().collect::<Vec<_>>(), vec![Unit]);
+}
+
+#[test]
+fn test_enum() {
+    let space = action_space(true, true, true);
+
+    assert!(!space.is_empty());
+    assert!(space.contains(&Action::Noop));
+    assert!(space.contains(&Action::Turn(-1)));
+    assert!(!space.contains(&Action::Turn(2)));
+    assert!(space.contains(&Action::Move { dx: 1, dy: 2 }));
+    assert!(!space.contains(&Action::Move { dx: 2, dy: 2 }));
+    assert!(!space.contains(&Action::Type(0, 1)));
+
+    assert_eq!(space.cardinality(), 1 + 3 + 6);
+
+    let elements: Vec<_> = space.elements().collect();
+
+    assert_eq!(elements.len(), space.cardinality());
+    assert_eq!(elements[0], Action::Noop);
+    assert_eq!(elements[4], Action::Move { dx: 0, dy: 0 });
+    assert!(elements.iter().all(|e| space.contains(e)));
+}
+
+#[test]
+fn test_empty_enum() {
+    let space = action_space(false, false, false);
+
+    assert!(NeverSpace {}.is_empty());
+    assert_eq!(NeverSpace {}.cardinality(), 0);
+    assert!(space.is_empty());
+    assert_eq!(space.cardinality(), 0);
+}
@@ -1,9 +1,14 @@
 use crate::{
     prelude::*,
-    ops::{UnionPair, IntersectionPair},
+    ops::{
+        ClosureOf, Complement, ComplementOf, DecidableEmptiness, DifferencePair,
+        IntersectionOf, IntersectionPair, SetRelations, UnionPair,
+    },
+    notation::{self, Notation, Precedence, Style},
+    project::{Metric, OpenBounds},
+    validate::{ContainmentError, PathSegment},
 };
-use std::{iter::Map, convert::TryInto};
-use itertools::{Itertools, structs::MultiProduct};
+use std::fmt;
 
 impl<const N: usize, D: Space> Space for [D; N] {
     type Value = [D::Value; N];
@@ -19,27 +24,300 @@ impl<const N: usize, D: FiniteSpace> FiniteSpace for [D; N] {
     fn cardinality(&self) -> usize { self.iter().map(|d| d.cardinality()).product() }
 }
 
-impl<const N: usize, D: IterableSpace> IterableSpace for [D; N]
+impl<const N: usize, D: IndexedSpace> IndexedSpace for [D; N] {
+    fn index_of(&self, val: &Self::Value) -> Option<usize> {
+        self.iter().zip(val.iter()).try_fold(0, |acc, (d, x)| {
+            d.index_of(x).map(|i| acc * d.cardinality() + i)
+        })
+    }
+
+    fn from_index(&self, index: usize) -> Option<Self::Value> {
+        if index >= self.cardinality() { return None; }
+
+        let mut digits = [0; N];
+        let mut rem = index;
+
+        for (digit, d) in digits.iter_mut().zip(self.iter()).rev() {
+            let radix = d.cardinality();
+
+            *digit = rem % radix;
+            rem /= radix;
+        }
+
+        let vals: [Option<D::Value>; N] = std::array::from_fn(|i| self[i].from_index(digits[i]));
+
+        if vals.iter().any(|v| v.is_none()) { return None; }
+
+        Some(vals.map(|v| v.unwrap()))
+    }
+}
+
+impl<const N: usize, D: Encode> Encode for [D; N] {
+    fn encoding_len(&self) -> usize { self.iter().map(|d| d.encoding_len()).sum() }
+
+    fn encode_into(&self, val: &Self::Value, out: &mut [f32]) {
+        assert_eq!(out.len(), self.encoding_len(), "encoding buffer has the wrong length");
+
+        let mut rest = out;
+
+        for (d, x) in self.iter().zip(val.iter()) {
+            let (head, tail) = rest.split_at_mut(d.encoding_len());
+
+            d.encode_into(x, head);
+            rest = tail;
+        }
+    }
+
+    fn decode(&self, features: &[f32]) -> Option<Self::Value> {
+        if features.len() != self.encoding_len() { return None; }
+
+        let mut offset = 0;
+        let vals: [Option<D::Value>; N] = std::array::from_fn(|i| {
+            let n = self[i].encoding_len();
+
+            offset += n;
+            self[i].decode(&features[(offset - n)..offset])
+        });
+
+        if vals.iter().any(|v| v.is_none()) { return None; }
+
+        Some(vals.map(|v| v.unwrap()))
+    }
+}
+
+impl<const N: usize, D: Measure> Measure for [D; N] {
+    fn measure(&self) -> f64 {
+        if self.is_empty() { 0.0 } else { crate::product_measure(self.iter().map(|d| d.measure())) }
+    }
+}
+
+/// Odometer iterator over the elements of a product of finite spaces.
+///
+/// Each component is walked lazily with a clone of its element iterator; the
+/// last component varies fastest. Jumps with `nth` and steps from the back
+/// re-enter the components via `Iterator::nth`, so no elements are collected
+/// up front.
+#[derive(Clone, Debug)]
+pub struct ArrayIter<I, const N: usize> {
+    init: [I; N],
+    iters: [I; N],
+    radices: [usize; N],
+    front: [usize; N],
+    back: [usize; N],
+    len: usize,
+}
+
+impl<I: Iterator + Clone, const N: usize> ArrayIter<I, N> {
+    fn new(init: [I; N], radices: [usize; N]) -> ArrayIter<I, N> {
+        let len = if radices.contains(&0) {
+            0
+        } else {
+            radices.iter()
+                .try_fold(1usize, |acc, &r| acc.checked_mul(r))
+                .expect("number of elements overflows usize")
+        };
+        let mut iter = ArrayIter { iters: init.clone(), init, radices, front: [0; N], back: [0; N], len, };
+
+        if len > 0 { iter.back = iter.digits(len - 1); }
+
+        iter
+    }
+
+    fn digits(&self, mut index: usize) -> [usize; N] {
+        let mut digits = [0; N];
+
+        for (digit, &r) in digits.iter_mut().zip(self.radices.iter()).rev() {
+            *digit = index % r;
+            index /= r;
+        }
+
+        digits
+    }
+
+    fn offset(&self, digits: &[usize; N]) -> usize {
+        digits.iter().zip(self.radices.iter()).fold(0, |acc, (d, r)| acc * r + d)
+    }
+
+    // Move the front of each component iterator to the given digits.
+    fn seek(&mut self, digits: [usize; N]) {
+        for ((iter, init), (&to, &from)) in self.iters.iter_mut()
+            .zip(self.init.iter())
+            .zip(digits.iter().zip(self.front.iter()))
+        {
+            let skip = if to >= from {
+                to - from
+            } else {
+                *iter = init.clone();
+
+                to
+            };
+
+            if skip > 0 { iter.nth(skip - 1); }
+        }
+
+        self.front = digits;
+    }
+}
+
+impl<I: Iterator + Clone, const N: usize> Iterator for ArrayIter<I, N> {
+    type Item = [I::Item; N];
+
+    fn next(&mut self) -> Option<[I::Item; N]> {
+        if self.len == 0 { return None; }
+
+        let value = std::array::from_fn(|i| {
+            self.iters[i].clone().next().expect("component yielded fewer elements than its cardinality")
+        });
+
+        self.len -= 1;
+
+        if self.len > 0 {
+            for i in (0..N).rev() {
+                self.iters[i].next();
+                self.front[i] += 1;
+
+                if self.front[i] < self.radices[i] { break; }
+
+                self.front[i] = 0;
+                self.iters[i] = self.init[i].clone();
+            }
+        }
+
+        Some(value)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) { (self.len, Some(self.len)) }
+
+    fn nth(&mut self, n: usize) -> Option<[I::Item; N]> {
+        if n >= self.len {
+            self.len = 0;
+
+            return None;
+        }
+
+        let digits = self.digits(self.offset(&self.front) + n);
+
+        self.seek(digits);
+        self.len -= n;
+
+        self.next()
+    }
+}
+
+impl<I: Iterator + Clone, const N: usize> DoubleEndedIterator for ArrayIter<I, N> {
+    fn next_back(&mut self) -> Option<[I::Item; N]> {
+        if self.len == 0 { return None; }
+
+        let value = std::array::from_fn(|i| {
+            self.init[i].clone().nth(self.back[i]).expect("component yielded fewer elements than its cardinality")
+        });
+
+        self.len -= 1;
+
+        for (digit, &r) in self.back.iter_mut().zip(self.radices.iter()).rev() {
+            if *digit > 0 {
+                *digit -= 1;
+
+                break;
+            }
+
+            *digit = r - 1;
+        }
+
+        Some(value)
+    }
+}
+
+impl<I: Iterator + Clone, const N: usize> ExactSizeIterator for ArrayIter<I, N> {}
+
+impl<const N: usize, D> IterableSpace for [D; N]
 where
-    D::Value: Clone,
+    D: FiniteSpace + IterableSpace,
     D::ElemIter: Clone,
 {
-    // TODO - Ideally, we would replace MultiProduct with an optimised implementation
-    // for yielding arrays directly, not using an intermediate Vec.
-    type ElemIter = Map<
-        MultiProduct<D::ElemIter>,
-        fn(Vec<D::Value>) -> [D::Value; N]
-    >;
+    type ElemIter = ArrayIter<D::ElemIter, N>;
 
+    /// # Panics
+    ///
+    /// Panics if the number of elements overflows a `usize`.
     fn elements(&self) -> Self::ElemIter {
-        let iters: Vec<_> = self.iter().map(|s| s.elements()).collect();
+        ArrayIter::new(
+            std::array::from_fn(|i| self[i].elements()),
+            std::array::from_fn(|i| self[i].cardinality()),
+        )
+    }
+}
+
+impl<const N: usize, D: Project> Project for [D; N] {
+    fn project_with(&self, val: Self::Value, strategy: OpenBounds) -> Self::Value {
+        let mut val = IntoIterator::into_iter(val);
+
+        std::array::from_fn(|i| self[i].project_with(val.next().unwrap(), strategy))
+    }
+}
 
-        iters.into_iter().multi_cartesian_product().map(|x| {
-            x.try_into().map_err(|_| ()).unwrap()
+impl<const N: usize, D: Validate> Validate for [D; N] {
+    fn validate(&self, val: &Self::Value) -> Result<(), ContainmentError> {
+        self.iter().zip(val.iter()).enumerate().try_for_each(|(i, (d, x))| {
+            d.validate(x).map_err(|e| e.within(PathSegment::Index(i)))
         })
     }
 }
 
+impl<const N: usize, V: Metric> Metric for [V; N] {
+    fn distance(&self, other: &[V; N]) -> f64 {
+        self.iter().zip(other.iter()).map(|(x, y)| x.distance(y).powi(2)).sum::<f64>().sqrt()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<const N: usize, D: Sample> Sample for [D; N] {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Self::Value {
+        std::array::from_fn(|i| self[i].sample(rng))
+    }
+}
+
+impl<const N: usize, D, E> DecidableEmptiness<[E; N]> for [D; N]
+where
+    D: DecidableEmptiness<E>,
+    E: Space<Value = D::Value>,
+{
+    fn intersection_is_empty(&self, rhs: &[E; N]) -> bool {
+        self.iter().zip(rhs.iter()).any(|(d, e)| d.intersection_is_empty(e))
+    }
+}
+
+impl<const N: usize, D, A, B> DecidableEmptiness<UnionPair<A, B>> for [D; N]
+where
+    D: Space,
+    A: Space<Value = [D::Value; N]>,
+    B: Space<Value = [D::Value; N]>,
+
+    [D; N]: DecidableEmptiness<A> + DecidableEmptiness<B>,
+{
+    fn intersection_is_empty(&self, rhs: &UnionPair<A, B>) -> bool {
+        self.intersection_is_empty(&rhs.0) && self.intersection_is_empty(&rhs.1)
+    }
+}
+
+impl<const N: usize, D, E> SetRelations<[E; N]> for [D; N]
+where
+    D: SetRelations<E>,
+    E: Space<Value = D::Value>,
+{
+    fn is_subset_of(&self, rhs: &[E; N]) -> bool {
+        self.is_empty() || self.iter().zip(rhs.iter()).all(|(d, e)| d.is_subset_of(e))
+    }
+
+    fn is_superset_of(&self, rhs: &[E; N]) -> bool {
+        rhs.is_empty() || self.iter().zip(rhs.iter()).all(|(d, e)| d.is_superset_of(e))
+    }
+
+    fn is_disjoint_from(&self, rhs: &[E; N]) -> bool {
+        self.iter().zip(rhs.iter()).any(|(d, e)| d.is_disjoint_from(e))
+    }
+}
+
 impl<const N: usize, D, S> Union<S> for [D; N]
 where
     D: Space,
@@ -50,24 +328,99 @@ where
     fn union(self, rhs: S) -> Self::Output { UnionPair(self, rhs) }
 }
 
-impl<const N: usize, D, S> Intersection<S> for [D; N]
+impl<const N: usize, D, E> Intersection<[E; N]> for [D; N]
 where
-    D: Space,
-    S: Space<Value = [D::Value; N]>,
+    D: Intersection<E>,
+    E: Space<Value = D::Value>,
 {
-    type Output = IntersectionPair<Self, S>;
+    type Output = [IntersectionOf<D, E>; N];
+
+    fn intersect(self, rhs: [E; N]) -> Option<Self::Output> {
+        let mut rhs = IntoIterator::into_iter(rhs);
+        let components = self.map(|d| d.intersect(rhs.next().unwrap()));
+
+        if components.iter().any(|c| c.is_none()) { return None; }
 
-    fn intersect(self, rhs: S) -> Option<Self::Output> {
-        Some(IntersectionPair(self, rhs))
+        Some(components.map(|c| c.unwrap()))
     }
 }
 
+macro_rules! impl_intersect_pair {
+    ($($rhs:ident<$($p:ident),+>),+) => {$(
+        impl<const N: usize, D, $($p),+> Intersection<$rhs<$($p),+>> for [D; N]
+        where
+            D: Space,
+            $($p: Space<Value = [D::Value; N]>,)+
+            $rhs<$($p),+>: Space<Value = [D::Value; N]>,
+
+            IntersectionPair<[D; N], $rhs<$($p),+>>: Space,
+        {
+            type Output = IntersectionPair<Self, $rhs<$($p),+>>;
+
+            fn intersect(self, rhs: $rhs<$($p),+>) -> Option<Self::Output> {
+                let intersect = IntersectionPair(self, rhs);
+
+                if intersect.is_empty() { None } else { Some(intersect) }
+            }
+        }
+    )+}
+}
+
+impl_intersect_pair!(
+    UnionPair<A, B>, IntersectionPair<A, B>, DifferencePair<A, B>, ComplementOf<S>, Option<S>
+);
+
+impl<const N: usize, D> Complement for [D; N]
+where
+    D: Complement + Clone,
+{
+    type Output = ComplementOf<Self>;
+
+    fn complement(self) -> Self::Output { ComplementOf(self) }
+}
+
+impl<const N: usize, D> Space for ComplementOf<[D; N]>
+where
+    D: Complement + Clone,
+{
+    type Value = [D::Value; N];
+
+    fn is_empty(&self) -> bool { self.0.iter().all(|d| d.clone().complement().is_empty()) }
+
+    fn contains(&self, val: &Self::Value) -> bool { !self.0.contains(val) }
+}
+
 impl<const N: usize, D: Closure> Closure for [D; N] {
     type Output = [D::Output; N];
 
     fn closure(self) -> Self::Output { self.map(|d| d.closure()) }
 }
 
+impl<const N: usize, D, E> Closure for UnionPair<[D; N], [E; N]>
+where
+    D: Space,
+    E: Space<Value = D::Value>,
+
+    UnionPair<D, E>: Closure,
+    ClosureOf<UnionPair<D, E>>: Space<Value = D::Value>,
+{
+    type Output = [ClosureOf<UnionPair<D, E>>; N];
+
+    fn closure(self) -> Self::Output {
+        let mut rhs = IntoIterator::into_iter(self.1);
+
+        self.0.map(|d| UnionPair(d, rhs.next().unwrap()).closure())
+    }
+}
+
+impl<const N: usize, D: Notation> Notation for [D; N] {
+    fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+        notation::fmt_product(f, style, self)
+    }
+
+    fn precedence(&self) -> Precedence { notation::product_precedence(self) }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -91,6 +444,35 @@ mod tests {
         ].is_empty());
     }
 
+    #[test]
+    fn test_measure() {
+        assert_eq!([Interval::closed_unchecked(0.0f64, 2.0); 3].measure(), 8.0);
+        assert_eq!([Interval::closed_unchecked(0, 2); 3].measure(), 27.0);
+        assert_eq!([crate::real::reals::<f64>(); 2].measure(), f64::INFINITY);
+        assert_eq!([
+            Interval::closed_unchecked(0.0, f64::INFINITY),
+            Interval::degenerate(0.0),
+        ].measure(), 0.0);
+        assert_eq!([
+            Interval::open_unchecked(0.0f64, 0.0),
+            Interval::open_unchecked(1.0, 5.0),
+        ].measure(), 0.0);
+    }
+
+    #[test]
+    fn test_intersection_is_empty() {
+        let s = [Interval::unit(), Interval::unit()];
+
+        assert!(!s.intersection_is_empty(&[
+            Interval::closed_unchecked(0.5, 1.5),
+            Interval::closed_unchecked(-0.5, 0.5),
+        ]));
+        assert!(s.intersection_is_empty(&[
+            Interval::closed_unchecked(0.5, 1.5),
+            Interval::closed_unchecked(1.5, 2.5),
+        ]));
+    }
+
     #[test]
     fn test_contains() {
         let s = [
@@ -125,6 +507,14 @@ mod tests {
         ].cardinality(), 303);
     }
 
+    #[test]
+    fn test_project() {
+        let space = [Interval::closed_unchecked(0, 1), Interval::closed_unchecked(2, 3)];
+
+        assert_eq!(space.project([-1, 5]), [0, 3]);
+        assert_eq!(space.project([1, 2]), [1, 2]);
+    }
+
     #[test]
     fn test_values() {
         let space = [Interval::closed_unchecked(0, 1), Interval::closed_unchecked(2, 3)];
@@ -138,6 +528,17 @@ mod tests {
         ])
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample() {
+        let mut rng = rand::thread_rng();
+        let space = [Interval::closed_unchecked(0, 1), Interval::closed_unchecked(2, 3)];
+
+        for _ in 0..100 {
+            assert!(space.contains(&space.sample(&mut rng)));
+        }
+    }
+
     #[test]
     fn test_union() {
         let x = [
@@ -158,4 +559,133 @@ mod tests {
         assert!(!z.contains(&[0.0, 5.0]));
         assert!(!z.contains(&[1.0, 6.0]));
     }
+
+    #[test]
+    fn test_indexed() {
+        let s = [
+            Interval::closed_unchecked(0, 2),
+            Interval::closed_unchecked(5, 6),
+            Interval::closed_unchecked(-1, 0),
+        ];
+
+        assert_eq!(s.cardinality(), 12);
+        assert!(s.elements().enumerate().all(|(i, x)| {
+            s.index_of(&x) == Some(i) && s.from_index(i) == Some(x)
+        }));
+        assert_eq!(s.index_of(&[0, 7, 0]), None);
+        assert_eq!(s.from_index(12), None);
+        assert_eq!([Interval::open_unchecked(0, 1); 2].from_index(0), None);
+    }
+
+    #[test]
+    fn test_encode() {
+        let s = [Interval::closed_unchecked(0, 2), Interval::closed_unchecked(0, 1)];
+        let mut out = [1.0; 5];
+
+        s.encode_into(&[2, 0], &mut out);
+
+        assert_eq!(s.encoding_len(), 5);
+        assert_eq!(out, [0.0, 0.0, 1.0, 1.0, 0.0]);
+        assert_eq!(s.decode(&out), Some([2, 0]));
+        assert_eq!(s.decode(&[0.1, 0.5, 0.2, -1.0, -0.5]), Some([1, 1]));
+        assert_eq!(s.decode(&[0.0; 4]), None);
+    }
+
+    #[test]
+    fn test_elements_iter() {
+        let space = [
+            Interval::closed_unchecked(0, 2),
+            Interval::closed_unchecked(0, 1),
+            Interval::closed_unchecked(0, 3),
+        ];
+        let forward: Vec<_> = space.elements().collect();
+        let mut backward: Vec<_> = space.elements().rev().collect();
+
+        backward.reverse();
+
+        assert_eq!(space.elements().len(), 24);
+        assert_eq!(forward.len(), 24);
+        assert_eq!(forward, backward);
+        assert!(forward.iter().enumerate().all(|(i, x)| space.from_index(i) == Some(*x)));
+
+        let mut iter = space.elements();
+
+        assert_eq!(iter.nth(9), Some([1, 0, 1]));
+        assert_eq!(iter.len(), 14);
+        assert_eq!(iter.next_back(), Some([2, 1, 3]));
+        assert_eq!(iter.nth(11), Some([2, 1, 1]));
+        assert_eq!(iter.next(), Some([2, 1, 2]));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+
+        let mut iter = space.elements();
+
+        assert_eq!(iter.nth(24), None);
+        assert_eq!(iter.next(), None);
+        assert_eq!([Interval::open_unchecked(0, 1); 3].elements().next(), None);
+    }
+
+    #[test]
+    fn test_elements_lazy() {
+        let space = [Interval::closed_unchecked(0i64, 1 << 31); 2];
+        let mut iter = space.elements();
+
+        assert_eq!(iter.next(), Some([0, 0]));
+        assert_eq!(iter.nth((1 << 31) + 1), Some([1, 1]));
+        assert_eq!(iter.next_back(), Some([1 << 31, 1 << 31]));
+        assert_eq!(iter.next(), Some([1, 2]));
+        assert_eq!(iter.len(), ((1usize << 31) + 1).pow(2) - (1 << 31) - 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of elements overflows usize")]
+    fn test_elements_overflow() {
+        [Interval::closed_unchecked(0u32, 65535); 5].elements();
+    }
+
+    #[test]
+    fn test_intersect() {
+        let s = [Interval::closed_unchecked(0.0, 2.0), Interval::closed_unchecked(0.0, 1.0)];
+        let t = [Interval::closed_unchecked(1.0, 3.0), Interval::closed_unchecked(0.5, 4.0)];
+
+        assert_eq!(s.intersect(t), Some([
+            Interval::closed_unchecked(1.0, 2.0),
+            Interval::closed_unchecked(0.5, 1.0),
+        ]));
+        assert_eq!(s.intersect([
+            Interval::closed_unchecked(1.0, 3.0),
+            Interval::closed_unchecked(2.0, 4.0),
+        ]), None);
+    }
+
+    #[test]
+    fn test_intersect_union() {
+        let s = [Interval::closed_unchecked(0.0, 1.0); 2];
+        let u = UnionPair(
+            [Interval::closed_unchecked(0.5, 2.0); 2],
+            [Interval::closed_unchecked(3.0, 4.0); 2],
+        );
+
+        let i = s.intersect(u).unwrap();
+
+        assert!(i.contains(&[0.75, 1.0]));
+        assert!(!i.contains(&[0.25, 0.75]));
+        assert!(!i.contains(&[3.5, 3.5]));
+
+        assert!(s.intersect(UnionPair(
+            [Interval::closed_unchecked(2.0, 3.0); 2],
+            [Interval::closed_unchecked(-1.0, -0.5), Interval::closed_unchecked(0.0, 1.0)],
+        )).is_none());
+    }
+
+    #[test]
+    fn test_union_closure() {
+        let s = [Interval::closed_unchecked(0.0, 1.0), Interval::closed_unchecked(0.0, 1.0)];
+        let t = [Interval::closed_unchecked(2.0, 3.0), Interval::closed_unchecked(-1.0, 0.5)];
+
+        assert_eq!(s.union(t).closure(), [
+            Interval::closed_unchecked(0.0, 3.0),
+            Interval::closed_unchecked(-1.0, 1.0),
+        ]);
+    }
 }
@@ -18,6 +18,9 @@ pub fn integers<V: PrimInt + Signed>() -> Integers<V> { intervals::Interval::unb
 pub type Integers<V> = intervals::Unbounded<V>;
 
 /// Build a space representing the set of non-zero signed integers.
+///
+/// __Note__: `IntervalSet::from` may be used to convert this space into
+/// canonical form, truncated to the limits of `V`.
 pub fn non_zero_integers<V: PrimInt + Signed>() -> NonZeroIntegers<V> {
     let x = intervals::Interval::right_open(V::zero());
     let y = intervals::Interval::left_open(V::zero());
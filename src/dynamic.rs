@@ -0,0 +1,1066 @@
+//! Module for runtime-typed spaces.
+//!
+//! Static space types, such as `Interval<L, R>` or `[D; N]`, can be converted
+//! losslessly into a `SpaceDescriptor`, whose elements are represented by the
+//! `DynValue` enum. This allows spaces to be passed across boundaries where the
+//! concrete types are not known at compile time.
+//!
+//! ```
+//! # extern crate spaces;
+//! # use spaces::{dynamic::{DynSpace, DynValue, SpaceDescriptor}, intervals::Interval};
+//! let space: Box<dyn DynSpace> = Box::new(SpaceDescriptor::from((
+//!     Interval::closed_unchecked(0, 2),
+//!     [Interval::closed_unchecked(0.0, 1.0); 2],
+//! )));
+//!
+//! assert!(space.contains(&DynValue::from((1, [0.5, 0.5]))));
+//! assert!(!space.contains(&DynValue::from((3, [0.5, 0.5]))));
+//! assert_eq!(space.cardinality(), None);
+//! ```
+use crate::{
+    intervals::{bounds::{self, OpenOrClosed}, partitions, Interval},
+    ops::{ComplementOf, DifferencePair, IntersectionPair, UnionPair},
+    notation::{self, Notation, Precedence, Style},
+    Homogeneous,
+    IntervalSet,
+    OrderedSpace,
+    Space,
+};
+use std::{cmp::Ordering, collections::BTreeMap, convert::TryFrom, fmt, iter};
+
+///////////////////////////////////////////////////////////////////////////
+// Values
+///////////////////////////////////////////////////////////////////////////
+/// The kind of scalar stored in a `DynValue`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum ScalarKind {
+    /// Boolean values.
+    Bool,
+
+    /// Integer values, stored as `i128`.
+    Int,
+
+    /// Floating point values, stored as `f64`.
+    Float,
+
+    /// Single precision floating point values, also stored as `f64`.
+    Float32,
+}
+
+impl ScalarKind {
+    /// Return the kind of `DynValue` used to store scalars of this kind.
+    pub fn value_kind(self) -> ScalarKind {
+        match self {
+            ScalarKind::Float32 => ScalarKind::Float,
+            kind => kind,
+        }
+    }
+}
+
+/// Runtime-typed representation of the elements of a space.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum DynValue {
+    /// A boolean scalar.
+    Bool(bool),
+
+    /// An integer scalar.
+    Int(i128),
+
+    /// A floating point scalar.
+    Float(f64),
+
+    /// An element of a `Box` or `Homogeneous` space.
+    Vector(Vec<DynValue>),
+
+    /// An element of a `Tuple` space.
+    Tuple(Vec<DynValue>),
+
+    /// An element of a `Dict` space.
+    Dict(BTreeMap<String, DynValue>),
+}
+
+impl DynValue {
+    /// Return the kind of the value, if it is a scalar.
+    pub fn kind(&self) -> Option<ScalarKind> {
+        match self {
+            DynValue::Bool(_) => Some(ScalarKind::Bool),
+            DynValue::Int(_) => Some(ScalarKind::Int),
+            DynValue::Float(_) => Some(ScalarKind::Float),
+            _ => None,
+        }
+    }
+}
+
+/// Trait for scalar types with a lossless representation as a `DynValue`.
+///
+/// __Note__: `u128` is not supported as it does not fit into an `i128`.
+pub trait DynScalar: PartialOrd + Clone {
+    /// The kind of `DynValue` this type is converted into.
+    const KIND: ScalarKind;
+
+    /// Convert the scalar into a `DynValue`.
+    fn into_dyn(self) -> DynValue;
+}
+
+macro_rules! impl_dyn_scalar {
+    ($kind:ident; $($v:ty),+; |$x:ident| $conv:expr) => {$(
+        impl DynScalar for $v {
+            const KIND: ScalarKind = ScalarKind::$kind;
+
+            fn into_dyn(self) -> DynValue { let $x = self; DynValue::$kind($conv) }
+        }
+
+        impl From<$v> for DynValue {
+            fn from(x: $v) -> DynValue { x.into_dyn() }
+        }
+    )+}
+}
+
+impl_dyn_scalar!(Bool; bool; |x| x);
+impl_dyn_scalar!(Int; u8, u16, u32, u64, usize, i8, i16, i32, i64, i128, isize; |x| x as i128);
+impl_dyn_scalar!(Float; f64; |x| x);
+
+impl DynScalar for f32 {
+    const KIND: ScalarKind = ScalarKind::Float32;
+
+    fn into_dyn(self) -> DynValue { DynValue::Float(self as f64) }
+}
+
+impl From<f32> for DynValue {
+    fn from(x: f32) -> DynValue { x.into_dyn() }
+}
+
+impl<const N: usize, V: Into<DynValue>> From<[V; N]> for DynValue {
+    fn from(x: [V; N]) -> DynValue {
+        DynValue::Vector(IntoIterator::into_iter(x).map(Into::into).collect())
+    }
+}
+
+impl<V: Into<DynValue>> From<Vec<V>> for DynValue {
+    fn from(x: Vec<V>) -> DynValue { DynValue::Vector(x.into_iter().map(Into::into).collect()) }
+}
+
+impl fmt::Display for DynValue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let list = |f: &mut fmt::Formatter<'_>, xs: &[DynValue]| {
+            xs.iter().enumerate().try_for_each(|(i, x)| {
+                if i > 0 { write!(f, ", ")?; }
+
+                write!(f, "{}", x)
+            })
+        };
+
+        match self {
+            DynValue::Bool(x) => write!(f, "{}", x),
+            DynValue::Int(x) => write!(f, "{}", x),
+            // Debug formatting keeps the decimal point, e.g. `1.0`.
+            DynValue::Float(x) => write!(f, "{:?}", x),
+            DynValue::Vector(xs) => { write!(f, "[")?; list(f, xs)?; write!(f, "]") },
+            DynValue::Tuple(xs) => { write!(f, "(")?; list(f, xs)?; write!(f, ")") },
+            DynValue::Dict(xs) => {
+                write!(f, "{{")?;
+
+                for (i, (k, x)) in xs.iter().enumerate() {
+                    if i > 0 { write!(f, ", ")?; }
+
+                    write!(f, "{}: {}", k, x)?;
+                }
+
+                write!(f, "}}")
+            },
+        }
+    }
+}
+
+fn scalar_cmp(x: &DynValue, y: &DynValue) -> Option<Ordering> {
+    match (x, y) {
+        (DynValue::Bool(x), DynValue::Bool(y)) => x.partial_cmp(y),
+        (DynValue::Int(x), DynValue::Int(y)) => x.partial_cmp(y),
+        (DynValue::Float(x), DynValue::Float(y)) => x.partial_cmp(y),
+        _ => None,
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+// Descriptors
+///////////////////////////////////////////////////////////////////////////
+/// Runtime-typed description of a space.
+#[derive(Clone, PartialEq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum SpaceDescriptor {
+    /// The empty set.
+    Empty,
+
+    /// An interval of scalars; a missing bound is unbounded.
+    Interval {
+        kind: ScalarKind,
+        left: Option<OpenOrClosed<DynValue>>,
+        right: Option<OpenOrClosed<DynValue>>,
+    },
+
+    /// The set of indices `0..n`, with `DynValue::Int` elements.
+    Discrete(usize),
+
+    /// A uniform partition of `[left, right]` into `size` bins, with the bin
+    /// indices `0..size` as `DynValue::Int` elements.
+    UniformPartition { size: usize, left: DynValue, right: DynValue },
+
+    /// A partition of a range by the given bounds into `size` bins, with the
+    /// bin indices `0..size` as `DynValue::Int` elements.
+    DeclarativePartition { size: usize, bounds: Vec<DynValue> },
+
+    /// A product of spaces, with `DynValue::Vector` elements.
+    Box(Vec<SpaceDescriptor>),
+
+    /// The `len`-fold product of a single space, with `DynValue::Vector`
+    /// elements.
+    Homogeneous { space: std::boxed::Box<SpaceDescriptor>, len: usize },
+
+    /// A product of spaces, with `DynValue::Tuple` elements.
+    Tuple(Vec<SpaceDescriptor>),
+
+    /// A product of named spaces, with `DynValue::Dict` elements.
+    Dict(BTreeMap<String, SpaceDescriptor>),
+
+    /// The union of two spaces.
+    Union(std::boxed::Box<SpaceDescriptor>, std::boxed::Box<SpaceDescriptor>),
+
+    /// The intersection of two spaces.
+    Intersection(std::boxed::Box<SpaceDescriptor>, std::boxed::Box<SpaceDescriptor>),
+
+    /// The complement of a space.
+    Complement(std::boxed::Box<SpaceDescriptor>),
+}
+
+/// Object-safe trait for spaces whose type is only known at runtime.
+pub trait DynSpace {
+    /// Return a description of the space.
+    fn descriptor(&self) -> SpaceDescriptor;
+
+    /// Return true if the space contains no values.
+    ///
+    /// __Note__: the emptiness of an intersection or complement cannot always
+    /// be decided; in this case the space is assumed to be non-empty.
+    fn is_empty(&self) -> bool;
+
+    /// Returns true iff `val` is contained within the space.
+    fn contains(&self, val: &DynValue) -> bool;
+
+    /// Return the cardinality of the space, or `None` if it is not finite or
+    /// overflows a `usize`.
+    fn cardinality(&self) -> Option<usize>;
+
+    /// Return an iterator over the elements of the space, or `None` if the
+    /// space is not finite.
+    fn elements(&self) -> Option<std::boxed::Box<dyn Iterator<Item = DynValue> + '_>>;
+}
+
+type DynIter<'a> = std::boxed::Box<dyn Iterator<Item = DynValue> + 'a>;
+
+fn left_holds(bound: &Option<OpenOrClosed<DynValue>>, val: &DynValue) -> bool {
+    match bound {
+        None => true,
+        Some(OpenOrClosed::Open(l)) => scalar_cmp(val, l) == Some(Ordering::Greater),
+        Some(OpenOrClosed::Closed(l)) => matches!(
+            scalar_cmp(val, l), Some(Ordering::Greater) | Some(Ordering::Equal)
+        ),
+    }
+}
+
+fn right_holds(bound: &Option<OpenOrClosed<DynValue>>, val: &DynValue) -> bool {
+    match bound {
+        None => true,
+        Some(OpenOrClosed::Open(r)) => scalar_cmp(val, r) == Some(Ordering::Less),
+        Some(OpenOrClosed::Closed(r)) => matches!(
+            scalar_cmp(val, r), Some(Ordering::Less) | Some(Ordering::Equal)
+        ),
+    }
+}
+
+/// Return the least and greatest integers within integer-valued bounds, or
+/// `None` if either bound is not an integer.
+fn int_bounds(
+    left: &Option<OpenOrClosed<DynValue>>,
+    right: &Option<OpenOrClosed<DynValue>>,
+) -> Option<(Option<i128>, Option<i128>)> {
+    let lo = match left {
+        None => None,
+        Some(OpenOrClosed::Closed(DynValue::Int(x))) => Some(*x),
+        Some(OpenOrClosed::Open(DynValue::Int(x))) => Some(x.checked_add(1)?),
+        _ => return None,
+    };
+    let hi = match right {
+        None => None,
+        Some(OpenOrClosed::Closed(DynValue::Int(x))) => Some(*x),
+        Some(OpenOrClosed::Open(DynValue::Int(x))) => Some(x.checked_sub(1)?),
+        _ => return None,
+    };
+
+    Some((lo, hi))
+}
+
+fn interval_is_empty(
+    left: &Option<OpenOrClosed<DynValue>>,
+    right: &Option<OpenOrClosed<DynValue>>,
+) -> bool {
+    match (left, right) {
+        (Some(l), Some(r)) => {
+            let both_closed = matches!((l, r), (OpenOrClosed::Closed(_), OpenOrClosed::Closed(_)));
+
+            match scalar_cmp(bound_value(l), bound_value(r)) {
+                Some(Ordering::Greater) | None => true,
+                Some(Ordering::Equal) => !both_closed,
+                Some(Ordering::Less) => false,
+            }
+        },
+        _ => false,
+    }
+}
+
+fn bound_value(bound: &OpenOrClosed<DynValue>) -> &DynValue {
+    match bound {
+        OpenOrClosed::Open(x) | OpenOrClosed::Closed(x) => x,
+    }
+}
+
+/// Returns the tighter of two left bounds.
+fn max_left(
+    x: &Option<OpenOrClosed<DynValue>>,
+    y: &Option<OpenOrClosed<DynValue>>,
+) -> Option<OpenOrClosed<DynValue>> {
+    match (x, y) {
+        (Some(a), Some(b)) => match scalar_cmp(bound_value(a), bound_value(b)) {
+            Some(Ordering::Less) => Some(b.clone()),
+            Some(Ordering::Equal) if matches!(b, OpenOrClosed::Open(_)) => Some(b.clone()),
+            _ => Some(a.clone()),
+        },
+        (a, None) => a.clone(),
+        (None, b) => b.clone(),
+    }
+}
+
+/// Returns the tighter of two right bounds.
+fn min_right(
+    x: &Option<OpenOrClosed<DynValue>>,
+    y: &Option<OpenOrClosed<DynValue>>,
+) -> Option<OpenOrClosed<DynValue>> {
+    match (x, y) {
+        (Some(a), Some(b)) => match scalar_cmp(bound_value(a), bound_value(b)) {
+            Some(Ordering::Greater) => Some(b.clone()),
+            Some(Ordering::Equal) if matches!(b, OpenOrClosed::Open(_)) => Some(b.clone()),
+            _ => Some(a.clone()),
+        },
+        (a, None) => a.clone(),
+        (None, b) => b.clone(),
+    }
+}
+
+/// Returns the elements of the cartesian product of `spaces` in lexicographic
+/// order, if finite.
+///
+/// The factors are walked like the digits of an odometer: one that runs out is
+/// restarted from a fresh call to `elements`, so nothing is collected.
+fn product_elements<'a, I>(spaces: I) -> Option<impl Iterator<Item = Vec<DynValue>> + 'a>
+where
+    I: Iterator<Item = &'a SpaceDescriptor>,
+{
+    let spaces: Vec<_> = spaces.collect();
+    let mut iters = spaces.iter().map(|s| s.elements()).collect::<Option<Vec<_>>>()?;
+    let mut current: Option<Vec<DynValue>> = iters.iter_mut().map(|e| e.next()).collect();
+
+    Some(iter::from_fn(move || {
+        let out = current.take()?;
+        let mut next = out.clone();
+
+        for i in (0..iters.len()).rev() {
+            if let Some(x) = iters[i].next() {
+                next[i] = x;
+                current = Some(next);
+
+                break;
+            }
+
+            match spaces[i].elements().and_then(|mut e| e.next().map(|x| (e, x))) {
+                Some((e, x)) => { iters[i] = e; next[i] = x; },
+                None => break,
+            }
+        }
+
+        Some(out)
+    }))
+}
+
+fn product_cardinality<I: Iterator<Item = Option<usize>>>(cards: I) -> Option<usize> {
+    let cards: Vec<_> = cards.collect();
+
+    if cards.contains(&Some(0)) {
+        Some(0)
+    } else {
+        cards.into_iter().try_fold(1usize, |acc, c| c.and_then(|c| acc.checked_mul(c)))
+    }
+}
+
+fn intersection_is_empty(x: &SpaceDescriptor, y: &SpaceDescriptor) -> bool {
+    use self::SpaceDescriptor::*;
+
+    if x.is_empty() || y.is_empty() { return true; }
+
+    match (x, y) {
+        (Union(a, b), c) | (c, Union(a, b)) => {
+            intersection_is_empty(a, c) && intersection_is_empty(b, c)
+        },
+        (
+            Interval { kind: kx, left: lx, right: rx },
+            Interval { kind: ky, left: ly, right: ry },
+        ) => kx.value_kind() != ky.value_kind() || interval_is_empty(&max_left(lx, ly), &min_right(rx, ry)),
+        (Box(xs), Box(ys)) | (Tuple(xs), Tuple(ys)) => {
+            xs.len() != ys.len() ||
+                xs.iter().zip(ys.iter()).any(|(a, b)| intersection_is_empty(a, b))
+        },
+        (Homogeneous { space: a, len: m }, Homogeneous { space: b, len: n }) => {
+            m != n || intersection_is_empty(a, b)
+        },
+        (Dict(xs), Dict(ys)) => {
+            !xs.keys().eq(ys.keys()) ||
+                xs.values().zip(ys.values()).any(|(a, b)| intersection_is_empty(a, b))
+        },
+        _ => match (x.elements(), y.elements()) {
+            (Some(mut elements), _) => !elements.any(|e| y.contains(&e)),
+            (_, Some(mut elements)) => !elements.any(|e| x.contains(&e)),
+            _ => false,
+        },
+    }
+}
+
+impl DynSpace for SpaceDescriptor {
+    fn descriptor(&self) -> SpaceDescriptor { self.clone() }
+
+    fn is_empty(&self) -> bool {
+        use self::SpaceDescriptor::*;
+
+        match self {
+            Empty => true,
+            Interval { left, right, .. } => interval_is_empty(left, right),
+            Discrete(n) | UniformPartition { size: n, .. } | DeclarativePartition { size: n, .. } => *n == 0,
+            Box(spaces) | Tuple(spaces) => spaces.iter().any(|s| s.is_empty()),
+            Homogeneous { space, len } => *len > 0 && space.is_empty(),
+            Dict(spaces) => spaces.values().any(|s| s.is_empty()),
+            Union(a, b) => a.is_empty() && b.is_empty(),
+            Intersection(a, b) => intersection_is_empty(a, b),
+            Complement(a) => matches!(**a, Interval { left: None, right: None, .. }),
+        }
+    }
+
+    fn contains(&self, val: &DynValue) -> bool {
+        use self::SpaceDescriptor::*;
+
+        match (self, val) {
+            (Empty, _) => false,
+            (Interval { kind, left, right }, val) => {
+                val.kind() == Some(kind.value_kind()) && left_holds(left, val) && right_holds(right, val)
+            },
+            (
+                Discrete(n) | UniformPartition { size: n, .. } | DeclarativePartition { size: n, .. },
+                DynValue::Int(i),
+            ) => *i >= 0 && *i < *n as i128,
+            (Box(spaces), DynValue::Vector(vals)) | (Tuple(spaces), DynValue::Tuple(vals)) => {
+                spaces.len() == vals.len() &&
+                    spaces.iter().zip(vals.iter()).all(|(s, v)| s.contains(v))
+            },
+            (Homogeneous { space, len }, DynValue::Vector(vals)) => {
+                *len == vals.len() && vals.iter().all(|v| space.contains(v))
+            },
+            (Dict(spaces), DynValue::Dict(vals)) => {
+                spaces.keys().eq(vals.keys()) &&
+                    spaces.values().zip(vals.values()).all(|(s, v)| s.contains(v))
+            },
+            (Union(a, b), val) => a.contains(val) || b.contains(val),
+            (Intersection(a, b), val) => a.contains(val) && b.contains(val),
+            (Complement(a), val) => !a.contains(val),
+            _ => false,
+        }
+    }
+
+    fn cardinality(&self) -> Option<usize> {
+        use self::SpaceDescriptor::*;
+
+        if self.is_empty() { return Some(0); }
+
+        match self {
+            Discrete(n) | UniformPartition { size: n, .. } | DeclarativePartition { size: n, .. } => Some(*n),
+            Box(spaces) | Tuple(spaces) => product_cardinality(spaces.iter().map(|s| s.cardinality())),
+            Homogeneous { space, len } => space.cardinality().and_then(|c| {
+                u32::try_from(*len).ok().and_then(|len| c.checked_pow(len))
+            }),
+            Dict(spaces) => product_cardinality(spaces.values().map(|s| s.cardinality())),
+            _ => self.elements().map(|e| e.count()),
+        }
+    }
+
+    fn elements(&self) -> Option<DynIter<'_>> {
+        use self::SpaceDescriptor::*;
+
+        if self.is_empty() { return Some(std::boxed::Box::new(iter::empty())); }
+
+        match self {
+            Empty => Some(std::boxed::Box::new(iter::empty())),
+            Interval { kind: ScalarKind::Bool, .. } => Some(std::boxed::Box::new(
+                [false, true].iter().map(|&b| DynValue::Bool(b)).filter(move |b| self.contains(b))
+            )),
+            Interval {
+                kind: ScalarKind::Int,
+                left: Some(l),
+                right: Some(r),
+            } => match (bound_value(l), bound_value(r)) {
+                (&DynValue::Int(lo), &DynValue::Int(hi)) => Some(std::boxed::Box::new(
+                    (lo..=hi).map(DynValue::Int).filter(move |i| self.contains(i))
+                )),
+                _ => None,
+            },
+            Interval {
+                kind: ScalarKind::Float | ScalarKind::Float32,
+                left: Some(OpenOrClosed::Closed(l)),
+                right: Some(OpenOrClosed::Closed(r)),
+            } if l == r => Some(std::boxed::Box::new(iter::once(l.clone()))),
+            Interval { .. } => None,
+            Discrete(n) | UniformPartition { size: n, .. } | DeclarativePartition { size: n, .. } => {
+                Some(std::boxed::Box::new((0..*n as i128).map(DynValue::Int)))
+            },
+            Box(spaces) => product_elements(spaces.iter())
+                .map(|e| std::boxed::Box::new(e.map(DynValue::Vector)) as DynIter),
+            Homogeneous { space, len } => product_elements(iter::repeat(&**space).take(*len))
+                .map(|e| std::boxed::Box::new(e.map(DynValue::Vector)) as DynIter),
+            Tuple(spaces) => product_elements(spaces.iter())
+                .map(|e| std::boxed::Box::new(e.map(DynValue::Tuple)) as DynIter),
+            Dict(spaces) => product_elements(spaces.values()).map(|e| {
+                std::boxed::Box::new(e.map(move |vals| {
+                    DynValue::Dict(spaces.keys().cloned().zip(vals).collect())
+                })) as DynIter
+            }),
+            Union(a, b) => match (a.elements(), b.elements()) {
+                (Some(x), Some(y)) => Some(std::boxed::Box::new(
+                    x.chain(y.filter(move |v| !a.contains(v)))
+                )),
+                _ => None,
+            },
+            Intersection(a, b) => match a.elements() {
+                Some(x) => Some(std::boxed::Box::new(x.filter(move |v| b.contains(v)))),
+                None => b.elements().map(|y| {
+                    std::boxed::Box::new(y.filter(move |v| a.contains(v))) as DynIter
+                }),
+            },
+            Complement(_) => None,
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+// Notation
+///////////////////////////////////////////////////////////////////////////
+impl Notation for SpaceDescriptor {
+    fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+        use self::SpaceDescriptor::*;
+
+        let (open, close) = match style {
+            Style::Unicode => ("{", "}"),
+            Style::Latex => ("\\{", "\\}"),
+        };
+
+        match self {
+            Empty => notation::fmt_empty(f, style),
+            Interval {
+                kind: ScalarKind::Bool,
+                left: Some(OpenOrClosed::Closed(DynValue::Bool(l))),
+                right: Some(OpenOrClosed::Closed(DynValue::Bool(r))),
+            } if l <= r => {
+                let values: &[bool] = if l == r { &[*l] } else { &[false, true] };
+
+                write!(f, "{}", open)?;
+
+                for (i, v) in values.iter().enumerate() {
+                    if i > 0 { write!(f, ", ")?; }
+
+                    match style {
+                        Style::Unicode => write!(f, "{}", v)?,
+                        Style::Latex => write!(f, "\\mathrm{{{}}}", v)?,
+                    }
+                }
+
+                write!(f, "{}", close)
+            },
+            Interval { kind: ScalarKind::Int, left, right } if int_bounds(left, right).is_some() => {
+                let (lo, hi) = int_bounds(left, right).unwrap();
+
+                notation::fmt_int_range(f, style, lo, hi)
+            },
+            Interval { kind: ScalarKind::Float | ScalarKind::Float32, left: None, right: None } => match style {
+                Style::Unicode => write!(f, "\u{211D}"),
+                Style::Latex => write!(f, "\\mathbb{{R}}"),
+            },
+            Interval { left, right, .. } => {
+                notation::fmt_left(f, style, left.as_ref())?;
+                write!(f, ", ")?;
+                notation::fmt_right(f, style, right.as_ref())
+            },
+            Discrete(n) | UniformPartition { size: n, .. } | DeclarativePartition { size: n, .. } => {
+                notation::fmt_range(f, style, *n)
+            },
+            Box(spaces) => notation::fmt_product(f, style, spaces),
+            Homogeneous { len: 0, .. } => notation::fmt_empty_product(f, style),
+            Homogeneous { space, len } => notation::fmt_power(f, style, &**space, *len),
+            Tuple(spaces) if spaces.is_empty() => notation::fmt_empty_product(f, style),
+            Tuple(spaces) => notation::fmt_tuple(
+                f, style, &spaces.iter().map(|s| s as &dyn Notation).collect::<Vec<_>>()
+            ),
+            Dict(spaces) => {
+                write!(f, "{}", open)?;
+
+                for (i, (k, s)) in spaces.iter().enumerate() {
+                    if i > 0 { write!(f, ", ")?; }
+
+                    write!(f, "{}: ", k)?;
+                    s.fmt_notation(f, style)?;
+                }
+
+                write!(f, "{}", close)
+            },
+            Union(a, b) => notation::fmt_binary(f, style, &**a, &**b, Precedence::Union),
+            Intersection(a, b) => match &**b {
+                Complement(b) => notation::fmt_binary(f, style, &**a, &**b, Precedence::Difference),
+                b => notation::fmt_binary(f, style, &**a, b, Precedence::Intersection),
+            },
+            Complement(a) => {
+                notation::fmt_operand(f, &**a, style, Precedence::Atom)?;
+
+                match style {
+                    Style::Unicode => write!(f, "\u{1D9C}"),
+                    Style::Latex => write!(f, "^{{c}}"),
+                }
+            },
+        }
+    }
+
+    fn precedence(&self) -> Precedence {
+        use self::SpaceDescriptor::*;
+
+        match self {
+            Box(spaces) => notation::product_precedence(spaces),
+            Homogeneous { len, .. } if *len > 0 => Precedence::Power,
+            Tuple(spaces) if !spaces.is_empty() => Precedence::Product,
+            Union(_, _) => Precedence::Union,
+            Intersection(_, b) if matches!(**b, Complement(_)) => Precedence::Difference,
+            Intersection(_, _) => Precedence::Intersection,
+            Complement(_) => Precedence::Power,
+            _ => Precedence::Atom,
+        }
+    }
+}
+
+impl fmt::Display for SpaceDescriptor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.fmt_notation(f, Style::Unicode) }
+}
+
+///////////////////////////////////////////////////////////////////////////
+// Conversions
+///////////////////////////////////////////////////////////////////////////
+impl<L, R> From<Interval<L, R>> for SpaceDescriptor
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: DynScalar,
+{
+    fn from(interval: Interval<L, R>) -> SpaceDescriptor {
+        let to_dyn = |b: OpenOrClosed<L::Value>| match b {
+            OpenOrClosed::Open(x) => OpenOrClosed::Open(x.into_dyn()),
+            OpenOrClosed::Closed(x) => OpenOrClosed::Closed(x.into_dyn()),
+        };
+
+        SpaceDescriptor::Interval {
+            kind: L::Value::KIND,
+            left: interval.inf().map(to_dyn),
+            right: interval.sup().map(to_dyn),
+        }
+    }
+}
+
+impl<V: DynScalar> From<IntervalSet<V>> for SpaceDescriptor {
+    fn from(set: IntervalSet<V>) -> SpaceDescriptor {
+        set.segments()
+            .iter()
+            .cloned()
+            .map(SpaceDescriptor::from)
+            .reduce(|x, y| SpaceDescriptor::Union(std::boxed::Box::new(x), std::boxed::Box::new(y)))
+            .unwrap_or(SpaceDescriptor::Empty)
+    }
+}
+
+impl<V: DynScalar> From<partitions::Uniform<V>> for SpaceDescriptor {
+    fn from(p: partitions::Uniform<V>) -> SpaceDescriptor {
+        SpaceDescriptor::UniformPartition {
+            size: p.size,
+            left: p.left.into_dyn(),
+            right: p.right.into_dyn(),
+        }
+    }
+}
+
+impl<const N: usize, V: DynScalar> From<partitions::Declarative<N, V>> for SpaceDescriptor {
+    fn from(p: partitions::Declarative<N, V>) -> SpaceDescriptor {
+        SpaceDescriptor::DeclarativePartition {
+            size: crate::FiniteSpace::cardinality(&p),
+            bounds: IntoIterator::into_iter(p.0).map(DynScalar::into_dyn).collect(),
+        }
+    }
+}
+
+impl<const N: usize, D> From<[D; N]> for SpaceDescriptor
+where
+    SpaceDescriptor: From<D>,
+{
+    fn from(spaces: [D; N]) -> SpaceDescriptor {
+        SpaceDescriptor::Box(IntoIterator::into_iter(spaces).map(SpaceDescriptor::from).collect())
+    }
+}
+
+impl<D> From<Vec<D>> for SpaceDescriptor
+where
+    SpaceDescriptor: From<D>,
+{
+    fn from(spaces: Vec<D>) -> SpaceDescriptor {
+        SpaceDescriptor::Box(spaces.into_iter().map(SpaceDescriptor::from).collect())
+    }
+}
+
+impl<D> From<Homogeneous<D>> for SpaceDescriptor
+where
+    SpaceDescriptor: From<D>,
+{
+    fn from(h: Homogeneous<D>) -> SpaceDescriptor {
+        SpaceDescriptor::Homogeneous {
+            space: std::boxed::Box::new(SpaceDescriptor::from(h.space)),
+            len: h.len,
+        }
+    }
+}
+
+impl<S> From<Option<S>> for SpaceDescriptor
+where
+    SpaceDescriptor: From<S>,
+{
+    fn from(space: Option<S>) -> SpaceDescriptor {
+        space.map_or(SpaceDescriptor::Empty, SpaceDescriptor::from)
+    }
+}
+
+impl<A, B> From<UnionPair<A, B>> for SpaceDescriptor
+where
+    A: Space,
+    B: Space<Value = A::Value>,
+
+    SpaceDescriptor: From<A> + From<B>,
+{
+    fn from(pair: UnionPair<A, B>) -> SpaceDescriptor {
+        SpaceDescriptor::Union(
+            std::boxed::Box::new(pair.0.into()),
+            std::boxed::Box::new(pair.1.into()),
+        )
+    }
+}
+
+impl<A, B> From<IntersectionPair<A, B>> for SpaceDescriptor
+where
+    A: Space,
+    B: Space<Value = A::Value>,
+
+    SpaceDescriptor: From<A> + From<B>,
+{
+    fn from(pair: IntersectionPair<A, B>) -> SpaceDescriptor {
+        SpaceDescriptor::Intersection(
+            std::boxed::Box::new(pair.0.into()),
+            std::boxed::Box::new(pair.1.into()),
+        )
+    }
+}
+
+impl<A, B> From<DifferencePair<A, B>> for SpaceDescriptor
+where
+    A: Space,
+    B: Space<Value = A::Value>,
+
+    SpaceDescriptor: From<A> + From<B>,
+{
+    fn from(pair: DifferencePair<A, B>) -> SpaceDescriptor {
+        SpaceDescriptor::Intersection(
+            std::boxed::Box::new(pair.0.into()),
+            std::boxed::Box::new(SpaceDescriptor::Complement(std::boxed::Box::new(pair.1.into()))),
+        )
+    }
+}
+
+impl<S> From<ComplementOf<S>> for SpaceDescriptor
+where
+    S: Space,
+
+    SpaceDescriptor: From<S>,
+{
+    fn from(c: ComplementOf<S>) -> SpaceDescriptor {
+        SpaceDescriptor::Complement(std::boxed::Box::new(c.0.into()))
+    }
+}
+
+macro_rules! impl_tuple {
+    ($(($tp:ident, $vp:ident)::$i:tt),+) => {
+        impl<$($tp),+> From<($($tp),+)> for SpaceDescriptor
+        where
+            $(SpaceDescriptor: From<$tp>),+
+        {
+            fn from(spaces: ($($tp),+)) -> SpaceDescriptor {
+                SpaceDescriptor::Tuple(vec![$(spaces.$i.into()),+])
+            }
+        }
+
+        impl<$($vp: Into<DynValue>),+> From<($($vp),+)> for DynValue {
+            fn from(vals: ($($vp),+)) -> DynValue {
+                DynValue::Tuple(vec![$(vals.$i.into()),+])
+            }
+        }
+    }
+}
+
+impl_tuple!((D1, X1)::0, (D2, X2)::1);
+impl_tuple!((D1, X1)::0, (D2, X2)::1, (D3, X3)::2);
+impl_tuple!((D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3);
+impl_tuple!((D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4);
+impl_tuple!((D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5);
+impl_tuple!((D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5, (D7, X7)::6);
+impl_tuple!((D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5, (D7, X7)::6, (D8, X8)::7);
+impl_tuple!((D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5, (D7, X7)::6, (D8, X8)::7, (D9, X9)::8);
+impl_tuple!((D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5, (D7, X7)::6, (D8, X8)::7, (D9, X9)::8, (D10, X10)::9);
+impl_tuple!((D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5, (D7, X7)::6, (D8, X8)::7, (D9, X9)::8, (D10, X10)::9, (D11, X11)::10);
+impl_tuple!((D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5, (D7, X7)::6, (D8, X8)::7, (D9, X9)::8, (D10, X10)::9, (D11, X11)::10, (D12, X12)::11);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FiniteSpace;
+
+    #[test]
+    fn test_interval() {
+        let d = SpaceDescriptor::from(Interval::lorc_unchecked(0.0f64, 1.0));
+
+        assert_eq!(d, SpaceDescriptor::Interval {
+            kind: ScalarKind::Float,
+            left: Some(OpenOrClosed::Open(DynValue::Float(0.0))),
+            right: Some(OpenOrClosed::Closed(DynValue::Float(1.0))),
+        });
+
+        assert!(!d.is_empty());
+        assert!(!d.contains(&DynValue::Float(0.0)));
+        assert!(d.contains(&DynValue::Float(1.0)));
+        assert!(!d.contains(&DynValue::Int(1)));
+        assert!(d.cardinality().is_none());
+        assert!(d.elements().is_none());
+
+        let e = SpaceDescriptor::from(Interval::closed_unchecked(0.0f32, 1.0));
+
+        assert!(matches!(e, SpaceDescriptor::Interval { kind: ScalarKind::Float32, .. }));
+        assert!(e.contains(&DynValue::Float(0.5)));
+        assert!(!intersection_is_empty(&e, &d));
+    }
+
+    #[test]
+    fn test_integer_interval() {
+        let space = Interval::lcro_unchecked(-2i32, 2);
+        let d = SpaceDescriptor::from(space);
+
+        assert_eq!(d.cardinality(), Some(space.cardinality()));
+        assert_eq!(d.elements().unwrap().collect::<Vec<_>>(), vec![
+            DynValue::Int(-2), DynValue::Int(-1), DynValue::Int(0), DynValue::Int(1),
+        ]);
+        assert_eq!(SpaceDescriptor::from(crate::discrete::integers::<i64>()).cardinality(), None);
+        assert_eq!(SpaceDescriptor::from(crate::discrete::binary()).cardinality(), Some(2));
+    }
+
+    #[test]
+    fn test_products() {
+        let d = SpaceDescriptor::from([Interval::closed_unchecked(0, 1); 2]);
+
+        assert!(d.contains(&DynValue::from([0, 1])));
+        assert!(!d.contains(&DynValue::from([0, 2])));
+        assert!(!d.contains(&DynValue::from((0, 1))));
+        assert_eq!(d.cardinality(), Some(4));
+
+        let h = SpaceDescriptor::from(crate::Homogeneous::new(Interval::closed_unchecked(0, 1), 2));
+
+        assert_eq!(
+            d.elements().unwrap().collect::<Vec<_>>(),
+            h.elements().unwrap().collect::<Vec<_>>()
+        );
+
+        let t = SpaceDescriptor::from((Interval::closed_unchecked(0, 2), crate::discrete::binary()));
+
+        assert!(t.contains(&DynValue::from((2, true))));
+        assert_eq!(t.cardinality(), Some(6));
+        assert_eq!(SpaceDescriptor::Tuple(vec![]).cardinality(), Some(1));
+        assert_eq!(
+            SpaceDescriptor::Tuple(vec![]).elements().unwrap().collect::<Vec<_>>(),
+            vec![DynValue::Tuple(vec![])]
+        );
+
+        let big = SpaceDescriptor::Homogeneous { space: std::boxed::Box::new(SpaceDescriptor::Discrete(2)), len: 64 };
+
+        assert_eq!(big.cardinality(), None);
+
+        let mut last = vec![DynValue::Int(0); 64];
+
+        last[63] = DynValue::Int(1);
+
+        assert_eq!(
+            big.elements().unwrap().take(2).collect::<Vec<_>>(),
+            vec![DynValue::Vector(vec![DynValue::Int(0); 64]), DynValue::Vector(last)]
+        );
+        assert_eq!(SpaceDescriptor::Box(vec![big.clone(), big]).cardinality(), None);
+    }
+
+    #[test]
+    fn test_dict() {
+        let mut spaces = BTreeMap::new();
+
+        spaces.insert("lives".to_string(), SpaceDescriptor::from(Interval::closed_unchecked(0, 3)));
+        spaces.insert("alive".to_string(), SpaceDescriptor::from(crate::discrete::binary()));
+
+        let d = SpaceDescriptor::Dict(spaces);
+        let mut val = BTreeMap::new();
+
+        val.insert("lives".to_string(), DynValue::Int(2));
+        val.insert("alive".to_string(), DynValue::Bool(true));
+
+        assert!(d.contains(&DynValue::Dict(val.clone())));
+        assert_eq!(d.cardinality(), Some(8));
+
+        val.remove("alive");
+
+        assert!(!d.contains(&DynValue::Dict(val)));
+    }
+
+    #[test]
+    fn test_union_and_intersection() {
+        let u = SpaceDescriptor::from(crate::ops::UnionPair(
+            Interval::closed_unchecked(0, 2),
+            Interval::closed_unchecked(2, 3),
+        ));
+
+        assert_eq!(u.cardinality(), Some(4));
+        assert!(u.contains(&DynValue::Int(3)));
+
+        let i = SpaceDescriptor::from(crate::ops::IntersectionPair(
+            Interval::closed_unchecked(0.0, 2.0),
+            Interval::closed_unchecked(2.0, 3.0),
+        ));
+
+        assert!(!i.is_empty());
+        assert!(i.contains(&DynValue::Float(2.0)));
+        assert!(SpaceDescriptor::from(crate::ops::IntersectionPair(
+            Interval::closed_unchecked(0.0, 2.0),
+            Interval::lorc_unchecked(2.0, 3.0),
+        )).is_empty());
+    }
+
+    #[test]
+    fn test_option_and_sets() {
+        let e: Option<crate::intervals::Closed<f64>> = None;
+
+        assert_eq!(SpaceDescriptor::from(e), SpaceDescriptor::Empty);
+        assert!(SpaceDescriptor::from(crate::IntervalSet::<f64>::empty()).is_empty());
+
+        let s = SpaceDescriptor::from(crate::IntervalSet::from(crate::discrete::non_zero_integers::<i8>()));
+
+        assert_eq!(s.cardinality(), Some(255));
+        assert!(!s.contains(&DynValue::Int(0)));
+
+        let p = SpaceDescriptor::from(partitions::Uniform { size: 5, left: 0.0, right: 1.0 });
+
+        assert_eq!(p, SpaceDescriptor::UniformPartition {
+            size: 5,
+            left: DynValue::Float(0.0),
+            right: DynValue::Float(1.0),
+        });
+        assert_ne!(p, SpaceDescriptor::from(partitions::Uniform { size: 5, left: 2.0, right: 3.0 }));
+        assert!(p.contains(&DynValue::Int(4)));
+        assert!(!p.contains(&DynValue::Int(5)));
+        assert_eq!(p.cardinality(), Some(5));
+        assert_eq!(p.to_string(), "{0..4}");
+
+        let q = SpaceDescriptor::from(partitions::Declarative::new([0, 2, 5, 9]).unwrap());
+
+        assert_eq!(q, SpaceDescriptor::DeclarativePartition {
+            size: 2,
+            bounds: vec![DynValue::Int(0), DynValue::Int(2), DynValue::Int(5), DynValue::Int(9)],
+        });
+        assert_eq!(q.elements().unwrap().collect::<Vec<_>>(), vec![DynValue::Int(0), DynValue::Int(1)]);
+    }
+
+    #[test]
+    fn test_difference() {
+        let d = SpaceDescriptor::from(crate::ops::DifferencePair(
+            crate::real::reals::<f64>(),
+            Interval::closed_unchecked(-0.1, 0.1),
+        ));
+
+        assert!(!d.is_empty());
+        assert!(d.contains(&DynValue::Float(1.0)));
+        assert!(!d.contains(&DynValue::Float(0.0)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let d = SpaceDescriptor::from((
+            Interval::lorc_unchecked(0.0f64, 1.0),
+            Homogeneous::new(Interval::closed_unchecked(0i32, 2), 2),
+        ));
+        let json = serde_json::to_value(&d).unwrap();
+
+        assert_eq!(json, serde_json::json!({"Tuple": [
+            {"Interval": {
+                "kind": "Float",
+                "left": {"Open": {"Float": 0.0}},
+                "right": {"Closed": {"Float": 1.0}},
+            }},
+            {"Homogeneous": {
+                "space": {"Interval": {
+                    "kind": "Int",
+                    "left": {"Closed": {"Int": 0}},
+                    "right": {"Closed": {"Int": 2}},
+                }},
+                "len": 2,
+            }},
+        ]}));
+        assert_eq!(serde_json::from_value::<SpaceDescriptor>(json).unwrap(), d);
+
+        let v = DynValue::from((true, [1i32, 2], 0.5f64));
+        let json = serde_json::to_value(&v).unwrap();
+
+        assert_eq!(json, serde_json::json!({"Tuple": [
+            {"Bool": true},
+            {"Vector": [{"Int": 1}, {"Int": 2}]},
+            {"Float": 0.5},
+        ]}));
+        assert_eq!(serde_json::from_value::<DynValue>(json).unwrap(), v);
+    }
+}
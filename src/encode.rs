@@ -0,0 +1,96 @@
+use crate::{IndexedSpace, Space};
+
+/// Trait for spaces whose values can be encoded as fixed-length feature
+/// vectors.
+///
+/// Scalar finite spaces use a one-hot encoding over their indices, while
+/// products concatenate the encodings of their components, giving a
+/// multi-hot vector with one active feature per component.
+///
+/// ```
+/// # extern crate spaces;
+/// # use spaces::{Encode, discrete, intervals::Interval};
+/// let space = (discrete::binary(), Interval::closed_unchecked(0, 2));
+///
+/// assert_eq!(space.encoding_len(), 5);
+/// assert_eq!(space.encode(&(true, 1)), vec![0.0, 1.0, 0.0, 1.0, 0.0]);
+/// assert_eq!(space.decode(&[0.9, 0.1, 0.2, 0.3, 0.5]), Some((false, 2)));
+/// ```
+pub trait Encode: Space {
+    /// Return the length of the encoding of every value in the space.
+    fn encoding_len(&self) -> usize;
+
+    /// Write the encoding of `val` into `out`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `val` is not contained in the space, or if the length of
+    /// `out` differs from `encoding_len()`.
+    fn encode_into(&self, val: &Self::Value, out: &mut [f32]);
+
+    /// Return the encoding of `val`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `val` is not contained in the space.
+    fn encode(&self, val: &Self::Value) -> Vec<f32> {
+        let mut out = vec![0.0; self.encoding_len()];
+
+        self.encode_into(val, &mut out);
+
+        out
+    }
+
+    /// Recover a value from a feature vector by taking the argmax of each
+    /// one-hot block.
+    ///
+    /// Returns `None` if the length of `features` differs from
+    /// `encoding_len()`, or if any block consists entirely of `NaN`s.
+    fn decode(&self, features: &[f32]) -> Option<Self::Value>;
+}
+
+/// Write the one-hot encoding of the index of `val` into `out`.
+pub(crate) fn one_hot_into<S: IndexedSpace>(space: &S, val: &S::Value, out: &mut [f32]) {
+    assert_eq!(out.len(), space.cardinality(), "encoding buffer has the wrong length");
+
+    let index = space.index_of(val).expect("value is not contained in the space");
+
+    out.iter_mut().for_each(|x| *x = 0.0);
+    out[index] = 1.0;
+}
+
+/// Decode a one-hot block by taking the index of its (first) largest entry.
+pub(crate) fn argmax_decode<S: IndexedSpace>(space: &S, features: &[f32]) -> Option<S::Value> {
+    if features.len() != space.cardinality() { return None; }
+
+    let index = features.iter().enumerate()
+        .filter(|(_, x)| !x.is_nan())
+        .fold(None, |best: Option<(usize, f32)>, (i, &x)| match best {
+            Some((_, y)) if y >= x => best,
+            _ => Some((i, x)),
+        })?.0;
+
+    space.from_index(index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intervals::Interval;
+
+    #[test]
+    fn test_argmax_decode() {
+        let s = Interval::closed_unchecked(1, 3);
+
+        assert_eq!(argmax_decode(&s, &[0.0, 2.0, 1.0]), Some(2));
+        assert_eq!(argmax_decode(&s, &[1.0, 1.0, f32::NAN]), Some(1));
+        assert_eq!(argmax_decode(&s, &[f32::NAN; 3]), None);
+        assert_eq!(argmax_decode(&s, &[1.0, 0.0]), None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_one_hot_not_contained() {
+        one_hot_into(&Interval::closed_unchecked(1, 3), &4, &mut [0.0; 3]);
+    }
+}
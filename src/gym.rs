@@ -0,0 +1,679 @@
+//! Module for exchanging space definitions with Python Gymnasium.
+//!
+//! Spaces are converted to and from `GymSpace` by way of a `SpaceDescriptor`.
+//! `GymSpace` serialises to the dictionary form of the corresponding
+//! Gymnasium space, tagged by `"type"`:
+//!
+//! - `{"type": "Box", "low": [..], "high": [..], "shape": [..], "dtype": "float64"}`
+//! - `{"type": "Discrete", "n": 5, "start": 0}`
+//! - `{"type": "MultiDiscrete", "nvec": [..], "start": [..], "shape": [..]}`
+//! - `{"type": "MultiBinary", "shape": [..]}`
+//! - `{"type": "Tuple", "spaces": [..]}`
+//! - `{"type": "Dict", "spaces": {..}}`
+//!
+//! Array-valued fields are flattened in row-major order and should be reshaped
+//! according to `shape`. Infinite `Box` bounds are written as `null`. A `Box`
+//! has `dtype` `"float32"` if its bounds are `f32`, and `"float64"` otherwise.
+//! A single boolean is exported as `Discrete` with `n = 2`, while arrays of
+//! booleans are exported as `MultiBinary`.
+//!
+//! ```
+//! # extern crate spaces;
+//! # use spaces::{gym::GymSpace, intervals::Interval};
+//! let space = GymSpace::from_space((
+//!     Interval::closed_unchecked(0, 3),
+//!     [Interval::closed_unchecked(-1.0, 1.0); 2],
+//! )).unwrap();
+//!
+//! assert_eq!(space, GymSpace::Tuple { spaces: vec![
+//!     GymSpace::Discrete { n: 4, start: 0 },
+//!     GymSpace::Box {
+//!         low: vec![-1.0, -1.0],
+//!         high: vec![1.0, 1.0],
+//!         shape: vec![2],
+//!         dtype: spaces::gym::Dtype::Float64,
+//!     },
+//! ]});
+//!
+//! // Open bounds have no Gymnasium equivalent:
+//! assert!(GymSpace::from_space(Interval::open_unchecked(0.0, 1.0)).is_err());
+//! ```
+use crate::{
+    dynamic::{DynValue, ScalarKind, SpaceDescriptor},
+    intervals::bounds::OpenOrClosed,
+};
+use serde_crate::{Deserialize, Deserializer, Serializer};
+use std::{collections::BTreeMap, convert::TryFrom, fmt};
+
+///////////////////////////////////////////////////////////////////////////
+// Types
+///////////////////////////////////////////////////////////////////////////
+/// Element type of a Gymnasium `Box` space, named as in numpy.
+#[derive(Copy, Clone, PartialEq, Eq, Debug, Serialize, Deserialize)]
+#[serde(crate = "serde_crate", rename_all = "lowercase")]
+pub enum Dtype {
+    Bool,
+    Int8,
+    Int16,
+    Int32,
+    Int64,
+    UInt8,
+    UInt16,
+    UInt32,
+    UInt64,
+    Float16,
+    Float32,
+    Float64,
+}
+
+impl Dtype {
+    /// Return the kind of scalar used to represent elements of this type.
+    pub fn kind(&self) -> ScalarKind {
+        match self {
+            Dtype::Bool => ScalarKind::Bool,
+            Dtype::Float32 => ScalarKind::Float32,
+            Dtype::Float16 | Dtype::Float64 => ScalarKind::Float,
+            _ => ScalarKind::Int,
+        }
+    }
+}
+
+/// Gymnasium-compatible description of a space.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(crate = "serde_crate", tag = "type")]
+pub enum GymSpace {
+    /// A (possibly unbounded) box in `R^n`.
+    Box {
+        #[serde(serialize_with = "serialize_bounds", deserialize_with = "deserialize_low")]
+        low: Vec<f64>,
+
+        #[serde(serialize_with = "serialize_bounds", deserialize_with = "deserialize_high")]
+        high: Vec<f64>,
+
+        shape: Vec<usize>,
+        dtype: Dtype,
+    },
+
+    /// The set of integers `start..start + n`.
+    Discrete {
+        n: u64,
+
+        #[serde(default)]
+        start: i64,
+    },
+
+    /// A product of `Discrete` spaces.
+    MultiDiscrete {
+        nvec: Vec<u64>,
+        start: Vec<i64>,
+        shape: Vec<usize>,
+    },
+
+    /// A product of binary spaces.
+    MultiBinary { shape: Vec<usize> },
+
+    /// A product of spaces, with tuple-valued elements.
+    Tuple { spaces: Vec<GymSpace> },
+
+    /// A product of named spaces, with dictionary-valued elements.
+    Dict { spaces: BTreeMap<String, GymSpace> },
+}
+
+/// Error type for spaces that cannot be exchanged with Gymnasium.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum GymError {
+    /// Real intervals with open bounds have no Gymnasium equivalent.
+    OpenBound,
+
+    /// Integer intervals must be bounded on both sides.
+    UnboundedInteger,
+
+    /// A product whose factors do not share a single `Box`, `MultiDiscrete`
+    /// or `MultiBinary` representation.
+    MixedProduct,
+
+    /// The number of bounds does not match the declared shape.
+    ShapeMismatch { expected: usize, found: usize },
+
+    /// A construct with no Gymnasium equivalent, such as a union.
+    Unsupported(&'static str),
+}
+
+impl fmt::Display for GymError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GymError::OpenBound => write!(f, "open interval bounds have no Gym equivalent"),
+            GymError::UnboundedInteger => write!(f, "integer intervals must be bounded"),
+            GymError::MixedProduct => write!(
+                f, "product of heterogeneous spaces cannot be represented as a Gym array space"
+            ),
+            GymError::ShapeMismatch { expected, found } => write!(
+                f, "expected {} values for the given shape, found {}", expected, found
+            ),
+            GymError::Unsupported(what) => write!(f, "{} has no Gym equivalent", what),
+        }
+    }
+}
+
+impl std::error::Error for GymError {}
+
+// Infinite bounds are encoded as `null`, as JSON has no representation for
+// them; the sign is implied by the field.
+fn serialize_bounds<S: Serializer>(bounds: &[f64], serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_seq(bounds.iter().map(|&b| if b.is_finite() { Some(b) } else { None }))
+}
+
+fn deserialize_low<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f64>, D::Error> {
+    Vec::<Option<f64>>::deserialize(deserializer)
+        .map(|bs| bs.into_iter().map(|b| b.unwrap_or(f64::NEG_INFINITY)).collect())
+}
+
+fn deserialize_high<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f64>, D::Error> {
+    Vec::<Option<f64>>::deserialize(deserializer)
+        .map(|bs| bs.into_iter().map(|b| b.unwrap_or(f64::INFINITY)).collect())
+}
+
+///////////////////////////////////////////////////////////////////////////
+// Export
+///////////////////////////////////////////////////////////////////////////
+impl GymSpace {
+    /// Convert a space into its Gymnasium description.
+    pub fn from_space<S>(space: S) -> Result<GymSpace, GymError>
+    where
+        SpaceDescriptor: From<S>,
+    {
+        GymSpace::try_from(&SpaceDescriptor::from(space))
+    }
+}
+
+fn as_float(bound: &DynValue) -> f64 {
+    match bound {
+        DynValue::Bool(x) => *x as u8 as f64,
+        DynValue::Int(x) => *x as f64,
+        DynValue::Float(x) => *x,
+        _ => f64::NAN,
+    }
+}
+
+fn as_int(bound: &DynValue) -> Result<i128, GymError> {
+    match bound {
+        DynValue::Bool(x) => Ok(*x as i128),
+        DynValue::Int(x) => Ok(*x),
+        _ => Err(GymError::Unsupported("non-integer bound")),
+    }
+}
+
+fn real_interval(
+    dtype: Dtype,
+    left: &Option<OpenOrClosed<DynValue>>,
+    right: &Option<OpenOrClosed<DynValue>>,
+) -> Result<GymSpace, GymError> {
+    let bound = |b: &Option<OpenOrClosed<DynValue>>, unbounded: f64| match b {
+        None => Ok(unbounded),
+        Some(OpenOrClosed::Closed(x)) => Ok(as_float(x)),
+        Some(OpenOrClosed::Open(_)) => Err(GymError::OpenBound),
+    };
+
+    Ok(GymSpace::Box {
+        low: vec![bound(left, f64::NEG_INFINITY)?],
+        high: vec![bound(right, f64::INFINITY)?],
+        shape: vec![],
+        dtype,
+    })
+}
+
+fn integer_interval(
+    left: &Option<OpenOrClosed<DynValue>>,
+    right: &Option<OpenOrClosed<DynValue>>,
+) -> Result<GymSpace, GymError> {
+    // Open integer bounds are tightened to the adjacent closed bound.
+    let (lo, hi) = match (left, right) {
+        (Some(l), Some(r)) => (
+            match l {
+                OpenOrClosed::Open(x) => as_int(x)? + 1,
+                OpenOrClosed::Closed(x) => as_int(x)?,
+            },
+            match r {
+                OpenOrClosed::Open(x) => as_int(x)? - 1,
+                OpenOrClosed::Closed(x) => as_int(x)?,
+            },
+        ),
+        _ => return Err(GymError::UnboundedInteger),
+    };
+
+    if hi < lo { return Err(GymError::Unsupported("the empty set")); }
+
+    let out_of_range = || GymError::Unsupported("an integer interval beyond 64 bits");
+
+    Ok(GymSpace::Discrete {
+        n: u64::try_from(hi - lo + 1).map_err(|_| out_of_range())?,
+        start: i64::try_from(lo).map_err(|_| out_of_range())?,
+    })
+}
+
+fn binary_interval(
+    left: &Option<OpenOrClosed<DynValue>>,
+    right: &Option<OpenOrClosed<DynValue>>,
+) -> Result<GymSpace, GymError> {
+    match (left, right) {
+        (
+            Some(OpenOrClosed::Closed(DynValue::Bool(false))),
+            Some(OpenOrClosed::Closed(DynValue::Bool(true))),
+        ) => Ok(GymSpace::MultiBinary { shape: vec![] }),
+        _ => Err(GymError::Unsupported("a boolean interval other than {false, true}")),
+    }
+}
+
+fn prepend(n: usize, shape: &[usize]) -> Vec<usize> {
+    let mut out = Vec::with_capacity(shape.len() + 1);
+
+    out.push(n);
+    out.extend_from_slice(shape);
+    out
+}
+
+/// Stack `factors` along a new leading axis.
+fn stack(factors: Vec<GymSpace>) -> Result<GymSpace, GymError> {
+    let n = factors.len();
+    let mut factors = factors.into_iter();
+
+    let first = match factors.next() {
+        Some(first) => first,
+        None => return Ok(GymSpace::Box {
+            low: vec![],
+            high: vec![],
+            shape: vec![0],
+            dtype: Dtype::Float64,
+        }),
+    };
+
+    match first {
+        GymSpace::Box { mut low, mut high, shape, dtype } => {
+            for f in factors {
+                match f {
+                    GymSpace::Box { low: l, high: h, shape: s, dtype: d } if s == shape && d == dtype => {
+                        low.extend(l);
+                        high.extend(h);
+                    },
+                    _ => return Err(GymError::MixedProduct),
+                }
+            }
+
+            Ok(GymSpace::Box { low, high, shape: prepend(n, &shape), dtype })
+        },
+        GymSpace::Discrete { n: m, start: s } => {
+            let mut nvec = vec![m];
+            let mut start = vec![s];
+
+            for f in factors {
+                match f {
+                    GymSpace::Discrete { n: m, start: s } => {
+                        nvec.push(m);
+                        start.push(s);
+                    },
+                    _ => return Err(GymError::MixedProduct),
+                }
+            }
+
+            Ok(GymSpace::MultiDiscrete { nvec, start, shape: vec![n] })
+        },
+        GymSpace::MultiDiscrete { mut nvec, mut start, shape } => {
+            for f in factors {
+                match f {
+                    GymSpace::MultiDiscrete { nvec: v, start: s, shape: sh } if sh == shape => {
+                        nvec.extend(v);
+                        start.extend(s);
+                    },
+                    _ => return Err(GymError::MixedProduct),
+                }
+            }
+
+            Ok(GymSpace::MultiDiscrete { nvec, start, shape: prepend(n, &shape) })
+        },
+        GymSpace::MultiBinary { shape } => {
+            if factors.all(|f| matches!(f, GymSpace::MultiBinary { shape: ref s } if *s == shape)) {
+                Ok(GymSpace::MultiBinary { shape: prepend(n, &shape) })
+            } else {
+                Err(GymError::MixedProduct)
+            }
+        },
+        GymSpace::Tuple { .. } | GymSpace::Dict { .. } => Err(GymError::MixedProduct),
+    }
+}
+
+// Converts a descriptor without resolving scalar booleans, which are kept as
+// zero-dimensional `MultiBinary` spaces so that they stack into arrays.
+fn to_gym(descriptor: &SpaceDescriptor) -> Result<GymSpace, GymError> {
+    match descriptor {
+        SpaceDescriptor::Empty => Err(GymError::Unsupported("the empty set")),
+        SpaceDescriptor::Interval { kind, left, right } => match kind {
+            ScalarKind::Bool => binary_interval(left, right),
+            ScalarKind::Int => integer_interval(left, right),
+            ScalarKind::Float => real_interval(Dtype::Float64, left, right),
+            ScalarKind::Float32 => real_interval(Dtype::Float32, left, right),
+        },
+        SpaceDescriptor::Discrete(0) |
+        SpaceDescriptor::UniformPartition { size: 0, .. } |
+        SpaceDescriptor::DeclarativePartition { size: 0, .. } => {
+            Err(GymError::Unsupported("the empty set"))
+        },
+        SpaceDescriptor::Discrete(n) |
+        SpaceDescriptor::UniformPartition { size: n, .. } |
+        SpaceDescriptor::DeclarativePartition { size: n, .. } => {
+            Ok(GymSpace::Discrete { n: *n as u64, start: 0 })
+        },
+        SpaceDescriptor::Box(spaces) => {
+            stack(spaces.iter().map(to_gym).collect::<Result<_, _>>()?)
+        },
+        SpaceDescriptor::Homogeneous { space, len } => {
+            stack(vec![to_gym(space)?; *len])
+        },
+        SpaceDescriptor::Tuple(spaces) => Ok(GymSpace::Tuple {
+            spaces: spaces.iter().map(GymSpace::try_from).collect::<Result<_, _>>()?,
+        }),
+        SpaceDescriptor::Dict(spaces) => Ok(GymSpace::Dict {
+            spaces: spaces
+                .iter()
+                .map(|(k, s)| GymSpace::try_from(s).map(|s| (k.clone(), s)))
+                .collect::<Result<_, _>>()?,
+        }),
+        SpaceDescriptor::Union(_, _) => Err(GymError::Unsupported("a union of spaces")),
+        SpaceDescriptor::Intersection(_, _) => {
+            Err(GymError::Unsupported("an intersection of spaces"))
+        },
+        SpaceDescriptor::Complement(_) => Err(GymError::Unsupported("a complement of a space")),
+    }
+}
+
+impl<'a> TryFrom<&'a SpaceDescriptor> for GymSpace {
+    type Error = GymError;
+
+    fn try_from(descriptor: &'a SpaceDescriptor) -> Result<GymSpace, GymError> {
+        to_gym(descriptor).map(|space| match space {
+            GymSpace::MultiBinary { ref shape } if shape.is_empty() => GymSpace::Discrete { n: 2, start: 0 },
+            space => space,
+        })
+    }
+}
+
+impl TryFrom<SpaceDescriptor> for GymSpace {
+    type Error = GymError;
+
+    fn try_from(descriptor: SpaceDescriptor) -> Result<GymSpace, GymError> {
+        GymSpace::try_from(&descriptor)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+// Import
+///////////////////////////////////////////////////////////////////////////
+/// Arrange row-major `leaves` into nested `Box` descriptors of the given shape.
+fn nest(shape: &[usize], leaves: Vec<SpaceDescriptor>) -> SpaceDescriptor {
+    match shape.split_first() {
+        None => leaves.into_iter().next().unwrap_or(SpaceDescriptor::Empty),
+        Some((&n, rest)) => {
+            // The shape has been checked, so this only saturates when `n` is zero.
+            let stride = rest.iter().fold(1usize, |acc, &m| acc.saturating_mul(m));
+            let mut leaves = leaves.into_iter();
+
+            SpaceDescriptor::Box((0..n).map(|_| nest(rest, leaves.by_ref().take(stride).collect())).collect())
+        },
+    }
+}
+
+fn shape_len(shape: &[usize]) -> Result<usize, GymError> {
+    shape.iter()
+        .try_fold(1usize, |acc, &n| acc.checked_mul(n))
+        .ok_or(GymError::Unsupported("a shape with more than usize::MAX elements"))
+}
+
+fn check_len(shape: &[usize], found: usize) -> Result<(), GymError> {
+    let expected = shape_len(shape)?;
+
+    if expected == found { Ok(()) } else { Err(GymError::ShapeMismatch { expected, found }) }
+}
+
+fn closed_interval(kind: ScalarKind, lo: DynValue, hi: DynValue) -> SpaceDescriptor {
+    SpaceDescriptor::Interval {
+        kind,
+        left: Some(OpenOrClosed::Closed(lo)),
+        right: Some(OpenOrClosed::Closed(hi)),
+    }
+}
+
+fn box_bound(kind: ScalarKind, x: f64) -> Option<OpenOrClosed<DynValue>> {
+    if x.is_infinite() { return None; }
+
+    Some(OpenOrClosed::Closed(match kind {
+        ScalarKind::Bool => DynValue::Bool(x != 0.0),
+        ScalarKind::Int => DynValue::Int(x as i128),
+        ScalarKind::Float | ScalarKind::Float32 => DynValue::Float(x),
+    }))
+}
+
+impl TryFrom<GymSpace> for SpaceDescriptor {
+    type Error = GymError;
+
+    fn try_from(space: GymSpace) -> Result<SpaceDescriptor, GymError> {
+        match space {
+            GymSpace::Box { low, high, shape, dtype } => {
+                check_len(&shape, low.len())?;
+                check_len(&shape, high.len())?;
+
+                let kind = dtype.kind();
+                let leaves = low.into_iter().zip(high).map(|(l, h)| SpaceDescriptor::Interval {
+                    kind,
+                    left: box_bound(kind, l),
+                    right: box_bound(kind, h),
+                }).collect();
+
+                Ok(nest(&shape, leaves))
+            },
+            GymSpace::Discrete { n, start: 0 } => Ok(SpaceDescriptor::Discrete(n as usize)),
+            GymSpace::Discrete { n: 0, .. } => Ok(SpaceDescriptor::Empty),
+            GymSpace::Discrete { n, start } => Ok(closed_interval(
+                ScalarKind::Int,
+                DynValue::Int(start as i128),
+                DynValue::Int(start as i128 + n as i128 - 1),
+            )),
+            GymSpace::MultiDiscrete { nvec, start, shape } => {
+                check_len(&shape, nvec.len())?;
+                check_len(&shape, start.len())?;
+
+                let leaves = nvec.into_iter().zip(start)
+                    .map(|(n, start)| SpaceDescriptor::try_from(GymSpace::Discrete { n, start }))
+                    .collect::<Result<_, _>>()?;
+
+                Ok(nest(&shape, leaves))
+            },
+            GymSpace::MultiBinary { shape } => {
+                let leaf = closed_interval(ScalarKind::Bool, DynValue::Bool(false), DynValue::Bool(true));
+
+                Ok(nest(&shape, vec![leaf; shape_len(&shape)?]))
+            },
+            GymSpace::Tuple { spaces } => spaces
+                .into_iter()
+                .map(SpaceDescriptor::try_from)
+                .collect::<Result<_, _>>()
+                .map(SpaceDescriptor::Tuple),
+            GymSpace::Dict { spaces } => spaces
+                .into_iter()
+                .map(|(k, s)| SpaceDescriptor::try_from(s).map(|s| (k, s)))
+                .collect::<Result<_, _>>()
+                .map(SpaceDescriptor::Dict),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{dynamic::DynSpace, intervals::Interval, ops::UnionPair, Homogeneous};
+
+    #[test]
+    fn test_scalars() {
+        assert_eq!(
+            GymSpace::from_space(Interval::closed_unchecked(-2, 2)).unwrap(),
+            GymSpace::Discrete { n: 5, start: -2 }
+        );
+        assert_eq!(
+            GymSpace::from_space(Interval::open_unchecked(-2, 2)).unwrap(),
+            GymSpace::Discrete { n: 3, start: -1 }
+        );
+        assert_eq!(
+            GymSpace::from_space(Interval::left_closed(0.0f64)).unwrap(),
+            GymSpace::Box { low: vec![0.0], high: vec![f64::INFINITY], shape: vec![], dtype: Dtype::Float64 }
+        );
+        assert_eq!(
+            GymSpace::from_space(Interval::closed_unchecked(-1.0f32, 1.0)).unwrap(),
+            GymSpace::Box { low: vec![-1.0], high: vec![1.0], shape: vec![], dtype: Dtype::Float32 }
+        );
+        assert_eq!(
+            GymSpace::from_space(Interval::closed_unchecked(false, true)).unwrap(),
+            GymSpace::Discrete { n: 2, start: 0 }
+        );
+        assert_eq!(
+            GymSpace::from_space((Interval::closed_unchecked(false, true), Interval::closed_unchecked(0, 1))).unwrap(),
+            GymSpace::Tuple { spaces: vec![
+                GymSpace::Discrete { n: 2, start: 0 },
+                GymSpace::Discrete { n: 2, start: 0 },
+            ]}
+        );
+    }
+
+    #[test]
+    fn test_products() {
+        let s = [[Interval::closed_unchecked(0.0, 1.0); 3]; 2];
+
+        assert_eq!(GymSpace::from_space(s).unwrap(), GymSpace::Box {
+            low: vec![0.0; 6],
+            high: vec![1.0; 6],
+            shape: vec![2, 3],
+            dtype: Dtype::Float64,
+        });
+
+        let s = Homogeneous::new(Interval::closed_unchecked(1u8, 4), 2);
+
+        assert_eq!(GymSpace::from_space(s).unwrap(), GymSpace::MultiDiscrete {
+            nvec: vec![4, 4],
+            start: vec![1, 1],
+            shape: vec![2],
+        });
+        assert_eq!(
+            GymSpace::from_space(vec![Interval::closed_unchecked(false, true); 4]).unwrap(),
+            GymSpace::MultiBinary { shape: vec![4] }
+        );
+
+        let s = [Interval::closed_unchecked(0.0f32, 1.0); 2];
+        let g = GymSpace::from_space(s).unwrap();
+
+        assert_eq!(g, GymSpace::Box { low: vec![0.0; 2], high: vec![1.0; 2], shape: vec![2], dtype: Dtype::Float32 });
+        assert_eq!(SpaceDescriptor::try_from(g).unwrap(), SpaceDescriptor::from(s));
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(GymSpace::from_space(Interval::lorc_unchecked(0.0, 1.0)), Err(GymError::OpenBound));
+        assert_eq!(GymSpace::from_space(Interval::left_closed(0i32)), Err(GymError::UnboundedInteger));
+        assert_eq!(
+            GymSpace::try_from(SpaceDescriptor::Box(vec![
+                Interval::closed_unchecked(0, 1).into(),
+                Interval::closed_unchecked(0.0, 1.0).into(),
+            ])),
+            Err(GymError::MixedProduct)
+        );
+        assert!(matches!(
+            GymSpace::from_space(UnionPair(Interval::closed_unchecked(0.0, 1.0), Interval::closed_unchecked(2.0, 3.0))),
+            Err(GymError::Unsupported(_))
+        ));
+        assert_eq!(
+            SpaceDescriptor::try_from(GymSpace::Box {
+                low: vec![0.0; 3],
+                high: vec![1.0; 3],
+                shape: vec![2, 2],
+                dtype: Dtype::Float32,
+            }),
+            Err(GymError::ShapeMismatch { expected: 4, found: 3 })
+        );
+        assert!(matches!(
+            SpaceDescriptor::try_from(GymSpace::MultiBinary { shape: vec![usize::MAX, 2] }),
+            Err(GymError::Unsupported(_))
+        ));
+        assert!(matches!(
+            SpaceDescriptor::try_from(GymSpace::MultiDiscrete {
+                nvec: vec![],
+                start: vec![],
+                shape: vec![usize::MAX, 2, 0],
+            }),
+            Err(GymError::Unsupported(_))
+        ));
+        assert_eq!(
+            SpaceDescriptor::try_from(GymSpace::MultiBinary { shape: vec![0, usize::MAX, 2] }),
+            Ok(SpaceDescriptor::Box(vec![]))
+        );
+    }
+
+    #[test]
+    fn test_import() {
+        let d = SpaceDescriptor::try_from(GymSpace::Box {
+            low: vec![f64::NEG_INFINITY, 0.0],
+            high: vec![0.0, f64::INFINITY],
+            shape: vec![2],
+            dtype: Dtype::Float32,
+        }).unwrap();
+
+        assert!(d.contains(&DynValue::from([-1e9, 1e9])));
+        assert!(!d.contains(&DynValue::from([1.0, 1.0])));
+
+        let d = SpaceDescriptor::try_from(GymSpace::MultiDiscrete {
+            nvec: vec![2, 3, 4, 5],
+            start: vec![0, 0, 1, 1],
+            shape: vec![2, 2],
+        }).unwrap();
+
+        assert_eq!(d.cardinality(), Some(120));
+        assert!(d.contains(&DynValue::Vector(vec![
+            DynValue::from([1, 2]),
+            DynValue::from([4, 5]),
+        ])));
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let d = SpaceDescriptor::from((
+            [Interval::closed_unchecked(-1.0, 1.0); 2],
+            [Interval::closed_unchecked(false, true); 3],
+        ));
+        let g = GymSpace::try_from(&d).unwrap();
+
+        assert_eq!(SpaceDescriptor::try_from(g).unwrap(), d);
+    }
+
+    #[test]
+    fn test_json() {
+        let g = GymSpace::from_space((
+            Interval::closed_unchecked(0, 4),
+            [crate::real::reals::<f64>(); 2],
+        )).unwrap();
+        let json = serde_json::to_value(&g).unwrap();
+
+        assert_eq!(json, serde_json::json!({
+            "type": "Tuple",
+            "spaces": [
+                {"type": "Discrete", "n": 5, "start": 0},
+                {
+                    "type": "Box",
+                    "low": [null, null],
+                    "high": [null, null],
+                    "shape": [2],
+                    "dtype": "float64",
+                },
+            ],
+        }));
+        assert_eq!(serde_json::from_value::<GymSpace>(json).unwrap(), g);
+
+        let g: GymSpace = serde_json::from_str(r#"{"type": "Discrete", "n": 3}"#).unwrap();
+
+        assert_eq!(g, GymSpace::Discrete { n: 3, start: 0 });
+    }
+}
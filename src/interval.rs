@@ -1,8 +1,18 @@
 use crate::{
-    Space, OrderedSpace, FiniteSpace, IterableSpace,
-    ops::{Union, UnionPair, Intersection, Closure}
+    Space, OrderedSpace, FiniteSpace, IndexedSpace, IterableSpace, Project, Validate,
+    ops::{
+        Union, UnionPair, Intersection, IntersectionOf, Closure,
+        Complement, DecidableEmptiness, Difference, SetRelations, Tighten,
+    },
+    notation::{self, Notation, Style},
+    project::{Nudge, OpenBounds},
+    validate::{self, ContainmentError},
+    encode::{self, Encode},
+};
+use std::{
+    convert::TryFrom,
+    ops::{RangeInclusive, RangeTo, RangeFrom, RangeToInclusive},
 };
-use std::ops::{RangeInclusive, RangeTo, RangeFrom, RangeToInclusive};
 use intervals::{Interval, bounds::{self, OpenOrClosed}};
 
 ///////////////////////////////////////////////////////////////////
@@ -19,7 +29,7 @@ where
 
     fn is_empty(&self) -> bool {
         match (self.left.value(), self.right.value()) {
-            (Some(l), Some(r)) if !self.left.is_closed() && !self.right.is_closed() => l == r,
+            (Some(l), Some(r)) => l > r || (l == r && !(self.left.is_closed() && self.right.is_closed())),
             _ => false,
         }
     }
@@ -64,76 +74,165 @@ where
     }
 }
 
-macro_rules! impl_fs {
-    ($v:ident; $left:ty, $right:ty; |$me:ident| $inner:block) => {
-        impl<$v> FiniteSpace for Interval<$left, $right>
-        where
-            $v: num_traits::PrimInt,
-            <$v as std::ops::Sub>::Output: num_traits::NumCast,
-        {
-            fn cardinality(&$me) -> usize {
-                num_traits::NumCast::from($inner).unwrap()
-            }
+impl<L, R> Project for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Nudge + Clone,
+{
+    fn project_with(&self, val: L::Value, strategy: OpenBounds) -> L::Value {
+        use OpenOrClosed::*;
+
+        let val = match self.inf() {
+            Some(Open(l)) if val <= l => l.nudge_up(strategy),
+            Some(Closed(l)) if val < l => l,
+            _ => val,
+        };
+
+        let val = match self.sup() {
+            Some(Open(r)) if val >= r => r.nudge_down(strategy),
+            Some(Closed(r)) if val > r => r,
+            _ => val,
+        };
+
+        // A fixed offset can overshoot a narrow interval, in which case we
+        // fall back to its midpoint.
+        match (self.left.value(), self.right.value()) {
+            (Some(l), Some(r)) if !Space::contains(self, &val) => l.clone().halfway_to(r.clone()),
+            _ => val,
         }
     }
 }
 
-#[inline]
-fn card_oo<V: num_traits::PrimInt>(left: V, right: V) -> <V as std::ops::Sub>::Output {
-    let d = right - left;
+impl<L, R> Validate for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
 
-    if d <= V::one() { V::zero() } else { d }
+    L::Value: Clone + std::fmt::Debug,
+{
+    fn validate(&self, val: &L::Value) -> Result<(), ContainmentError> {
+        validate::check_bounds(self.inf(), self.sup(), val)
+    }
 }
 
-#[inline]
-fn card_co<V: num_traits::PrimInt>(left: V, right: V) -> <V as std::ops::Sub>::Output {
-    right - left
+///////////////////////////////////////////////////////////////////
+// Finite Implementations
+///////////////////////////////////////////////////////////////////
+/// Trait for discrete scalar types whose values can be counted in order.
+pub trait Countable: PartialOrd + Copy {
+    /// Return the value `n` places after `self`, if representable.
+    fn forward(self, n: usize) -> Option<Self>;
+
+    /// Return the value `n` places before `self`, if representable.
+    fn backward(self, n: usize) -> Option<Self>;
+
+    /// Return the number of places from `start` up to `self`, if `self` is
+    /// not less than `start` and the distance fits in a `usize`.
+    fn distance_from(self, start: Self) -> Option<usize>;
 }
 
-#[inline]
-fn card_cc<V: num_traits::PrimInt>(left: V, right: V) -> <V as std::ops::Sub>::Output {
-    right - left + V::one()
+macro_rules! impl_countable {
+    ($($v:ty => $w:ty),+) => {$(
+        impl Countable for $v {
+            fn forward(self, n: usize) -> Option<$v> {
+                (self as $w).checked_add(n as $w).and_then(|x| <$v>::try_from(x).ok())
+            }
+
+            fn backward(self, n: usize) -> Option<$v> {
+                (self as $w).checked_sub(n as $w).and_then(|x| <$v>::try_from(x).ok())
+            }
+
+            fn distance_from(self, start: $v) -> Option<usize> {
+                (self as $w).checked_sub(start as $w).and_then(|d| usize::try_from(d).ok())
+            }
+        }
+    )+}
 }
 
-impl_fs!(V; bounds::Closed<V>, bounds::Closed<V>; |self| { card_cc(self.left.0, self.right.0) });
-impl_fs!(V; bounds::Closed<V>, bounds::Open<V>; |self| { card_co(self.left.0, self.right.0) });
-impl_fs!(V; bounds::Closed<V>, bounds::OpenOrClosed<V>; |self| {
-    match self.right {
-        bounds::OpenOrClosed::Open(r) => card_co(self.left.0, r),
-        bounds::OpenOrClosed::Closed(r) => card_cc(self.left.0, r),
-    }
-});
+impl_countable!(
+    u8 => i128, u16 => i128, u32 => i128, u64 => i128, usize => i128, u128 => u128,
+    i8 => i128, i16 => i128, i32 => i128, i64 => i128, isize => i128, i128 => i128
+);
 
-impl_fs!(V; bounds::Open<V>, bounds::Closed<V>; |self| { card_co(self.left.0, self.right.0) });
-impl_fs!(V; bounds::Open<V>, bounds::Open<V>; |self| { card_oo(self.left.0, self.right.0) });
-impl_fs!(V; bounds::Open<V>, bounds::OpenOrClosed<V>; |self| {
-    match self.right {
-        bounds::OpenOrClosed::Open(r) => card_oo(self.left.0, r),
-        bounds::OpenOrClosed::Closed(r) => card_co(self.left.0, r),
+impl Countable for bool {
+    fn forward(self, n: usize) -> Option<bool> {
+        (self as u8).forward(n).filter(|&x| x <= 1).map(|x| x == 1)
     }
-});
 
-impl_fs!(V; bounds::OpenOrClosed<V>, bounds::Closed<V>; |self| {
-    match self.left {
-        bounds::OpenOrClosed::Open(l) => card_co(l, self.right.0),
-        bounds::OpenOrClosed::Closed(l) => card_cc(l, self.right.0),
-    }
-});
-impl_fs!(V; bounds::OpenOrClosed<V>, bounds::Open<V>; |self| {
-    match self.left {
-        bounds::OpenOrClosed::Open(l) => card_oo(l, self.right.0),
-        bounds::OpenOrClosed::Closed(l) => card_co(l, self.right.0),
+    fn backward(self, n: usize) -> Option<bool> {
+        (self as u8).backward(n).map(|x| x == 1)
     }
-});
-impl_fs!(V; bounds::OpenOrClosed<V>, bounds::OpenOrClosed<V>; |self| {
-    use intervals::bounds::OpenOrClosed::{Open, Closed};
 
-    match (self.left, self.right) {
-        (Open(l), Open(r)) => card_oo(l, r),
-        (Closed(l), Open(r)) | (Open(l), Closed(r)) => card_co(l, r),
-        (Closed(l), Closed(r)) => card_cc(l, r),
+    fn distance_from(self, start: bool) -> Option<usize> {
+        (self as u8).distance_from(start as u8)
     }
-});
+}
+
+/// Return the least and greatest elements of a non-empty, bounded interval.
+fn extremes<L, R>(interval: &Interval<L, R>) -> Option<(L::Value, L::Value)>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Countable,
+{
+    let lo = match interval.inf()? {
+        OpenOrClosed::Open(l) => l.forward(1)?,
+        OpenOrClosed::Closed(l) => l,
+    };
+    let hi = match interval.sup()? {
+        OpenOrClosed::Open(r) => r.backward(1)?,
+        OpenOrClosed::Closed(r) => r,
+    };
+
+    if lo <= hi { Some((lo, hi)) } else { None }
+}
+
+macro_rules! impl_fs {
+    ($($left:ident, $right:ident);+) => {$(
+        impl<V: Countable> FiniteSpace for Interval<bounds::$left<V>, bounds::$right<V>> {
+            /// # Panics
+            ///
+            /// Panics if the number of elements overflows a `usize`, as it does
+            /// for the interval of all `u64` values on a 64-bit target.
+            fn cardinality(&self) -> usize {
+                extremes(self).map_or(0, |(lo, hi)| {
+                    hi.distance_from(lo)
+                        .and_then(|d| d.checked_add(1))
+                        .expect("cardinality overflows usize")
+                })
+            }
+        }
+
+        impl<V: Countable> IndexedSpace for Interval<bounds::$left<V>, bounds::$right<V>> {
+            fn index_of(&self, val: &V) -> Option<usize> {
+                if Space::contains(self, val) { val.distance_from(extremes(self)?.0) } else { None }
+            }
+
+            fn from_index(&self, index: usize) -> Option<V> {
+                let (lo, hi) = extremes(self)?;
+
+                lo.forward(index).filter(|x| *x <= hi)
+            }
+        }
+
+        impl<V: Countable> Encode for Interval<bounds::$left<V>, bounds::$right<V>> {
+            fn encoding_len(&self) -> usize { self.cardinality() }
+
+            fn encode_into(&self, val: &V, out: &mut [f32]) { encode::one_hot_into(self, val, out) }
+
+            fn decode(&self, features: &[f32]) -> Option<V> { encode::argmax_decode(self, features) }
+        }
+    )+}
+}
+
+impl_fs!(
+    Closed, Closed; Closed, Open; Closed, OpenOrClosed;
+    Open, Closed; Open, Open; Open, OpenOrClosed;
+    OpenOrClosed, Closed; OpenOrClosed, Open; OpenOrClosed, OpenOrClosed
+);
 
 ///////////////////////////////////////////////////////////////////
 // Iter Implementations
@@ -222,7 +321,7 @@ impl_iter!(V; bounds::OpenOrClosed<V>, bounds::Open<V>; |self| -> RangeInclusive
     let r = self.right.0 - V::one();
 
     match self.left {
-        OpenOrClosed::Open(l) => (l + V::one())..=(r - V::one()),
+        OpenOrClosed::Open(l) => (l + V::one())..=r,
         OpenOrClosed::Closed(l) => l..=r,
     }
 });
@@ -294,6 +393,125 @@ where
     }
 }
 
+///////////////////////////////////////////////////////////////////
+// Measure Implementations
+///////////////////////////////////////////////////////////////////
+/// Trait for scalar types over which the length of an interval is defined.
+///
+/// Real types are measured by the Lebesgue measure and discrete types by the
+/// counting measure.
+pub trait Length: PartialOrd + Sized {
+    /// Return the length of the non-empty interval with the given limits.
+    fn length(inf: OpenOrClosed<Self>, sup: OpenOrClosed<Self>) -> f64;
+}
+
+macro_rules! impl_length_lebesgue {
+    ($($v:ty),+) => {$(
+        impl Length for $v {
+            fn length(inf: OpenOrClosed<$v>, sup: OpenOrClosed<$v>) -> f64 {
+                (sup.unwrap() as f64) - (inf.unwrap() as f64)
+            }
+        }
+    )+}
+}
+
+impl_length_lebesgue!(f32, f64);
+
+macro_rules! impl_length_counting {
+    ($($v:ty),+) => {$(
+        impl Length for $v {
+            fn length(inf: OpenOrClosed<$v>, sup: OpenOrClosed<$v>) -> f64 {
+                let lo = match inf {
+                    OpenOrClosed::Open(l) => l as f64 + 1.0,
+                    OpenOrClosed::Closed(l) => l as f64,
+                };
+                let hi = match sup {
+                    OpenOrClosed::Open(r) => r as f64 - 1.0,
+                    OpenOrClosed::Closed(r) => r as f64,
+                };
+
+                (hi - lo + 1.0).max(0.0)
+            }
+        }
+    )+}
+}
+
+impl_length_counting!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Length for bool {
+    fn length(inf: OpenOrClosed<bool>, sup: OpenOrClosed<bool>) -> f64 {
+        let to_u8 = |b: OpenOrClosed<bool>| match b {
+            OpenOrClosed::Open(x) => OpenOrClosed::Open(x as u8),
+            OpenOrClosed::Closed(x) => OpenOrClosed::Closed(x as u8),
+        };
+
+        Length::length(to_u8(inf), to_u8(sup))
+    }
+}
+
+impl<L, R> crate::Measure for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Length + Clone,
+{
+    fn measure(&self) -> f64 {
+        if self.is_empty() { return 0.0; }
+
+        match (self.inf(), self.sup()) {
+            (Some(l), Some(r)) => Length::length(l, r),
+            _ => f64::INFINITY,
+        }
+    }
+}
+
+///////////////////////////////////////////////////////////////////
+// Sample Implementations
+///////////////////////////////////////////////////////////////////
+#[cfg(feature = "rand")]
+impl<L, R> crate::Sample for Interval<L, R>
+where
+    L: bounds::ProperBound,
+    R: bounds::ProperBound<Value = L::Value>,
+
+    L::Value: rand::distributions::uniform::SampleUniform + Nudge + Clone,
+{
+    fn sample<G: rand::Rng + ?Sized>(&self, rng: &mut G) -> L::Value {
+        let l = self.left.proper_value();
+        let r = self.right.proper_value();
+
+        // Open left bounds are handled by rejection; the limit point has
+        // measure zero, so this terminates almost surely for non-empty spaces.
+        match (self.left.is_open(), self.right.is_open()) {
+            (false, false) => rng.gen_range(l.clone()..=r.clone()),
+            (false, true) => rng.gen_range(l.clone()..r.clone()),
+            (true, false) => {
+                assert!(l < r, "Cannot sample from an empty interval.");
+
+                loop {
+                    let x = rng.gen_range(l.clone()..=r.clone());
+
+                    if &x > l { break x; }
+                }
+            },
+            (true, true) => {
+                // Discrete values may leave nothing strictly between l and r.
+                assert!(
+                    l.clone().nudge_up(OpenBounds::NextRepresentable) < *r,
+                    "Cannot sample from an empty interval."
+                );
+
+                loop {
+                    let x = rng.gen_range(l.clone()..r.clone());
+
+                    if &x > l { break x; }
+                }
+            },
+        }
+    }
+}
+
 ///////////////////////////////////////////////////////////////////
 // Op Implementations
 ///////////////////////////////////////////////////////////////////
@@ -336,6 +554,202 @@ where
     }
 }
 
+impl<L, R, LL, RR> DecidableEmptiness<Interval<LL, RR>> for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    LL: bounds::Bound<Value = L::Value>,
+    RR: bounds::Bound<Value = L::Value>,
+
+    L::Value: Tighten + Clone,
+{
+    fn intersection_is_empty(&self, rhs: &Interval<LL, RR>) -> bool {
+        crate::ops::convex_disjoint(self, rhs)
+    }
+}
+
+impl<L, R, LL, RR> SetRelations<Interval<LL, RR>> for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    LL: bounds::Bound<Value = L::Value>,
+    RR: bounds::Bound<Value = L::Value>,
+
+    L::Value: Tighten + Clone,
+{
+    fn is_subset_of(&self, rhs: &Interval<LL, RR>) -> bool {
+        crate::ops::convex_subset(self, rhs)
+    }
+
+    fn is_superset_of(&self, rhs: &Interval<LL, RR>) -> bool {
+        crate::ops::convex_subset(rhs, self)
+    }
+
+    fn is_disjoint_from(&self, rhs: &Interval<LL, RR>) -> bool {
+        crate::ops::convex_disjoint(self, rhs)
+    }
+}
+
+impl<L, R, A, B> DecidableEmptiness<UnionPair<A, B>> for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Clone,
+
+    A: Space<Value = L::Value>,
+    B: Space<Value = L::Value>,
+
+    Interval<L, R>: DecidableEmptiness<A> + DecidableEmptiness<B>,
+{
+    fn intersection_is_empty(&self, rhs: &UnionPair<A, B>) -> bool {
+        self.intersection_is_empty(&rhs.0) && self.intersection_is_empty(&rhs.1)
+    }
+}
+
+impl<L, R, A, B> Intersection<UnionPair<A, B>> for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Clone,
+
+    A: Space<Value = L::Value>,
+    B: Space<Value = L::Value>,
+
+    Interval<L, R>: Clone + Intersection<A> + Intersection<B>,
+    IntersectionOf<Interval<L, R>, B>: Space<
+        Value = <IntersectionOf<Interval<L, R>, A> as Space>::Value
+    >,
+{
+    type Output = UnionPair<
+        Option<IntersectionOf<Self, A>>,
+        Option<IntersectionOf<Self, B>>
+    >;
+
+    fn intersect(self, rhs: UnionPair<A, B>) -> Option<Self::Output> {
+        match (self.clone().intersect(rhs.0), self.intersect(rhs.1)) {
+            (None, None) => None,
+            (x, y) => Some(UnionPair(x, y)),
+        }
+    }
+}
+
+impl<L, R, S> Intersection<Option<S>> for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Clone,
+
+    S: Space<Value = L::Value>,
+
+    Interval<L, R>: Intersection<S>,
+{
+    type Output = IntersectionOf<Self, S>;
+
+    fn intersect(self, rhs: Option<S>) -> Option<Self::Output> {
+        rhs.and_then(|s| self.intersect(s))
+    }
+}
+
+/// Trait for bounds that can be exchanged between open and closed.
+///
+/// This is used to compute the complement of an interval: the limit point of
+/// the bound is included in exactly one of the interval or its complement.
+pub trait Flip: bounds::Bound {
+    type Flipped: bounds::Bound<Value = Self::Value>;
+
+    fn flip(self) -> Self::Flipped;
+}
+
+impl<V: PartialOrd> Flip for bounds::Open<V> {
+    type Flipped = bounds::Closed<V>;
+
+    fn flip(self) -> bounds::Closed<V> { bounds::Closed(self.0) }
+}
+
+impl<V: PartialOrd> Flip for bounds::Closed<V> {
+    type Flipped = bounds::Open<V>;
+
+    fn flip(self) -> bounds::Open<V> { bounds::Open(self.0) }
+}
+
+impl<V: PartialOrd> Flip for OpenOrClosed<V> {
+    type Flipped = OpenOrClosed<V>;
+
+    fn flip(self) -> OpenOrClosed<V> {
+        match self {
+            OpenOrClosed::Open(x) => OpenOrClosed::Closed(x),
+            OpenOrClosed::Closed(x) => OpenOrClosed::Open(x),
+        }
+    }
+}
+
+impl<V: PartialOrd + Clone> Complement for intervals::Unbounded<V> {
+    type Output = Option<Self>;
+
+    fn complement(self) -> Option<Self> { None }
+}
+
+impl<L> Complement for Interval<L, bounds::NoBound<L::Value>>
+where
+    L: Flip,
+    L::Value: Clone,
+{
+    type Output = Interval<bounds::NoBound<L::Value>, L::Flipped>;
+
+    fn complement(self) -> Self::Output { Interval::right_bounded(self.left.flip()) }
+}
+
+impl<R> Complement for Interval<bounds::NoBound<R::Value>, R>
+where
+    R: Flip,
+    R::Value: Clone,
+{
+    type Output = Interval<R::Flipped, bounds::NoBound<R::Value>>;
+
+    fn complement(self) -> Self::Output { Interval::left_bounded(self.right.flip()) }
+}
+
+impl<L, R> Complement for Interval<L, R>
+where
+    L: Flip,
+    R: Flip<Value = L::Value>,
+
+    L::Value: Clone,
+{
+    type Output = UnionPair<
+        Interval<bounds::NoBound<L::Value>, L::Flipped>,
+        Interval<R::Flipped, bounds::NoBound<L::Value>>
+    >;
+
+    fn complement(self) -> Self::Output {
+        UnionPair(
+            Interval::right_bounded(self.left.flip()),
+            Interval::left_bounded(self.right.flip()),
+        )
+    }
+}
+
+impl<L, R, T> Difference<T> for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Clone,
+
+    T: Complement<Value = L::Value>,
+
+    Interval<L, R>: Intersection<T::Output>,
+{
+    type Output = IntersectionOf<Self, T::Output>;
+
+    fn difference(self, rhs: T) -> Option<Self::Output> { self.intersect(rhs.complement()) }
+}
+
 impl<L, R> Closure for Interval<L, R>
 where
     L: bounds::Bound,
@@ -371,6 +785,23 @@ where
     }
 }
 
+///////////////////////////////////////////////////////////////////
+// Notation Implementations
+///////////////////////////////////////////////////////////////////
+impl<L, R> Notation for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Clone + std::fmt::Display,
+{
+    fn fmt_notation(&self, f: &mut std::fmt::Formatter<'_>, style: Style) -> std::fmt::Result {
+        notation::fmt_left(f, style, self.inf().as_ref())?;
+        write!(f, ", ")?;
+        notation::fmt_right(f, style, self.sup().as_ref())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -389,6 +820,164 @@ mod tests {
         assert_eq!(a.intersect(c), None);
     }
 
+    #[test]
+    fn test_is_empty() {
+        assert!(Interval::open_unchecked(0.0, 0.0).is_empty());
+        assert!(Interval::lcro_unchecked(0.0, 0.0).is_empty());
+        assert!(Interval::closed_unchecked(1.0, 0.0).is_empty());
+        assert!(!Interval::degenerate(0.0).is_empty());
+        assert!(!Interval::left_open(0.0).is_empty());
+    }
+
+    #[test]
+    fn test_intersection_is_empty() {
+        let a = Interval::closed_unchecked(0.0, 1.0);
+
+        assert!(!a.intersection_is_empty(&a));
+        assert!(!a.intersection_is_empty(&Interval::right_closed(0.0)));
+        assert!(a.intersection_is_empty(&Interval::right_open(0.0)));
+        assert!(a.intersection_is_empty(&Interval::open_unchecked(0.5, 0.5)));
+
+        assert!(!a.intersection_is_empty(&UnionPair(
+            Interval::right_open(0.0),
+            Interval::left_closed(1.0)
+        )));
+        assert!(a.intersection_is_empty(&UnionPair(
+            Interval::right_open(0.0),
+            Interval::left_open(1.0)
+        )));
+    }
+
+    #[test]
+    fn test_intersection_is_empty_discrete() {
+        let a = Interval::closed_unchecked(0i32, 5);
+
+        assert!(Interval::open_unchecked(0i32, 1).intersection_is_empty(&a));
+        assert!(!Interval::open_unchecked(0i32, 2).intersection_is_empty(&a));
+        assert!(Interval::left_open(5i32).intersection_is_empty(&a));
+
+        assert!(Interval::open_unchecked(false, true).intersection_is_empty(&Interval::closed_unchecked(false, true)));
+    }
+
+    #[test]
+    fn test_set_relations() {
+        let unit = Interval::closed_unchecked(0.0f64, 1.0);
+        let reals = crate::real::reals::<f64>();
+
+        assert!(unit.is_subset_of(&reals));
+        assert!(reals.is_superset_of(&unit));
+        assert!(!unit.is_superset_of(&reals));
+
+        assert!(unit.is_subset_of(&Interval::right_closed(1.0)));
+        assert!(!unit.is_subset_of(&Interval::right_open(1.0)));
+        assert!(Interval::open_unchecked(0.0, 1.0).is_subset_of(&Interval::lorc_unchecked(0.0, 1.0)));
+        assert!(!Interval::left_closed(0.0).is_subset_of(&Interval::left_closed(1.0)));
+
+        assert!(unit.is_disjoint_from(&Interval::left_open(1.0)));
+        assert!(!unit.is_disjoint_from(&Interval::left_closed(1.0)));
+
+        assert!(unit.set_eq(&Interval::new_unchecked(
+            bounds::OpenOrClosed::Closed(0.0),
+            bounds::OpenOrClosed::Closed(1.0)
+        )));
+        assert!(!unit.set_eq(&Interval::lcro_unchecked(0.0, 1.0)));
+        assert!(Interval::open_unchecked(1.0, 0.0).set_eq(&Interval::open_unchecked(5.0, 2.0)));
+    }
+
+    #[test]
+    fn test_set_relations_discrete() {
+        assert!(Interval::lcro_unchecked(0i32, 2).set_eq(&Interval::closed_unchecked(0, 1)));
+        assert!(Interval::open_unchecked(0u8, 3).set_eq(&Interval::closed_unchecked(1, 2)));
+        assert!(Interval::open_unchecked(0i32, 1).is_subset_of(&Interval::closed_unchecked(5, 6)));
+        assert!(!Interval::closed_unchecked(0i32, 1).is_subset_of(&Interval::open_unchecked(0, 1)));
+
+        assert!(Interval::lorc_unchecked(false, true).set_eq(&Interval::closed_unchecked(true, true)));
+    }
+
+    #[test]
+    fn test_measure() {
+        use crate::Measure;
+
+        assert_eq!(Interval::closed_unchecked(-1.0f64, 1.5).measure(), 2.5);
+        assert_eq!(Interval::open_unchecked(-1.0f32, 1.5).measure(), 2.5);
+        assert_eq!(Interval::open_unchecked(0.0f64, 0.0).measure(), 0.0);
+        assert_eq!(Interval::left_closed(0.0f64).measure(), f64::INFINITY);
+
+        assert_eq!(Interval::closed_unchecked(0u8, 255).measure(), 256.0);
+        assert_eq!(Interval::open_unchecked(-2i32, 2).measure(), 3.0);
+        assert_eq!(Interval::lcro_unchecked(-2i32, 2).measure(), 4.0);
+        assert_eq!(Interval::open_unchecked(0i32, 1).measure(), 0.0);
+        assert_eq!(crate::discrete::binary().measure(), 2.0);
+        assert_eq!(crate::discrete::integers::<i64>().measure(), f64::INFINITY);
+    }
+
+    #[test]
+    fn test_complement() {
+        let a = Interval::left_closed(0.0f64).complement();
+        let b = Interval::right_open(0.0f64).complement();
+        let c = Interval::lcro_unchecked(0.0f64, 1.0).complement();
+
+        assert_eq!(a, Interval::right_open(0.0));
+        assert_eq!(b, Interval::left_closed(0.0));
+        assert_eq!(c, UnionPair(Interval::right_open(0.0), Interval::left_closed(1.0)));
+        assert!(crate::real::reals::<f64>().complement().is_empty());
+
+        let d = Interval::new_unchecked(
+            bounds::OpenOrClosed::Open(0.0f64),
+            bounds::OpenOrClosed::Closed(1.0)
+        ).complement();
+
+        assert!(d.contains(&0.0));
+        assert!(!d.contains(&0.5));
+        assert!(!d.contains(&1.0));
+    }
+
+    #[test]
+    fn test_difference() {
+        let dead_zone = Interval::closed_unchecked(-0.1, 0.1);
+        let d = crate::real::reals::<f64>().difference(dead_zone).unwrap();
+
+        assert!(d.contains(&-1.0));
+        assert!(!d.contains(&-0.1));
+        assert!(!d.contains(&0.0));
+        assert!(!d.contains(&0.1));
+        assert!(d.contains(&1.0));
+
+        let e = Interval::closed_unchecked(0.0, 1.0).difference(Interval::left_closed(0.5)).unwrap();
+
+        assert_eq!(e, Interval::new_unchecked(
+            bounds::Closed(0.0),
+            bounds::OpenOrClosed::Open(0.5)
+        ));
+        assert!(Interval::unit().difference(crate::real::reals::<f64>()).is_none());
+        assert!(Interval::unit().difference(Interval::closed_unchecked(-1.0, 2.0)).is_none());
+    }
+
+    #[test]
+    fn test_project() {
+        let a = Interval::closed_unchecked(0.0, 1.0);
+        let b = Interval::open_unchecked(0.0, 1.0);
+        let c = Interval::left_open(0);
+
+        assert_eq!(a.project(-1.0), 0.0);
+        assert_eq!(a.project(0.5), 0.5);
+        assert_eq!(a.project(2.0), 1.0);
+
+        assert!(b.contains(b.project(-1.0)));
+        assert!(b.contains(b.project(2.0)));
+        assert_eq!(b.project_with(-1.0, OpenBounds::Epsilon(0.25)), 0.25);
+        assert_eq!(b.project_with(2.0, OpenBounds::Epsilon(0.25)), 0.75);
+
+        let d = Interval::open_unchecked(0.0, 0.1);
+
+        assert_eq!(d.project_with(-1.0, OpenBounds::Epsilon(0.25)), 0.05);
+        assert_eq!(d.project_with(1.0, OpenBounds::Epsilon(0.25)), 0.05);
+        assert_eq!(d.project_with(0.02, OpenBounds::Epsilon(0.25)), 0.02);
+
+        assert_eq!(c.project(-5), 1);
+        assert_eq!(c.project(5), 5);
+    }
+
     #[test]
     fn test_iter_cc() {
         let vals: Vec<_> = Interval::closed_unchecked(0, 5).elements().collect();
@@ -452,6 +1041,33 @@ mod tests {
         assert_eq!(vals, vec![1, 2, 3, 4, 5]);
     }
 
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample() {
+        use crate::Sample;
+
+        let mut rng = rand::thread_rng();
+
+        let s = Interval::closed_unchecked(0.0, 1.0);
+        let t = Interval::open_unchecked(0, 2);
+        let u = Interval::lorc_unchecked(0, 1);
+
+        for _ in 0..100 {
+            assert!(Space::contains(&s, &s.sample(&mut rng)));
+            assert_eq!(t.sample(&mut rng), 1);
+            assert_eq!(u.sample(&mut rng), 1);
+        }
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    #[should_panic]
+    fn test_sample_empty_open() {
+        use crate::Sample;
+
+        Interval::open_unchecked(0i32, 1).sample(&mut rand::thread_rng());
+    }
+
     #[test]
     fn test_iter_oo() {
         let vals: Vec<_> = Interval::open_unchecked(0, 5).elements().collect();
@@ -472,4 +1088,46 @@ mod tests {
 
         assert_eq!(vals, vec![1, 2, 3, 4]);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let a = Interval::left_closed(0.0f64);
+        let json = serde_json::to_string(&a).unwrap();
+
+        assert_eq!(json, r#"{"left":0.0,"right":null}"#);
+        assert_eq!(serde_json::from_str::<Interval<bounds::Closed<f64>, bounds::NoBound<f64>>>(&json).unwrap(), a);
+    }
+
+    #[test]
+    fn test_indexed() {
+        let a = Interval {
+            left: bounds::OpenOrClosed::Open(-2i8),
+            right: bounds::Open(3i8),
+        };
+
+        assert_eq!(a.cardinality(), 4);
+        assert!(a.elements().enumerate().all(|(i, x)| {
+            a.index_of(&x) == Some(i) && a.from_index(i) == Some(x)
+        }));
+        assert_eq!(a.elements().count(), 4);
+        assert_eq!(a.index_of(&3), None);
+        assert_eq!(a.from_index(4), None);
+
+        assert_eq!(Interval::open_unchecked(0u8, 1).cardinality(), 0);
+        assert_eq!(Interval::closed_unchecked(-128i8, 127).cardinality(), 256);
+
+        let b = crate::discrete::binary();
+
+        assert_eq!(b.cardinality(), 2);
+        assert_eq!(b.index_of(&true), Some(1));
+        assert_eq!(b.from_index(0), Some(false));
+        assert_eq!(b.from_index(2), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "cardinality overflows usize")]
+    fn test_cardinality_overflow() {
+        Interval::closed_unchecked(i128::MIN, i128::MAX).cardinality();
+    }
 }
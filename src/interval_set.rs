@@ -0,0 +1,558 @@
+use crate::{
+    FiniteSpace, IterableSpace, OrderedSpace, Space,
+    intervals::{Interval, bounds::{self, Bound, OpenOrClosed}},
+    interval::{Flip, Length},
+    notation::{self, Notation, Precedence, Style},
+    ops::{Closure, DecidableEmptiness, Difference, Intersection, Tighten, Union, UnionPair},
+};
+use std::{cmp::Ordering, convert::TryFrom, ops::RangeInclusive};
+
+/// Alias for the bounded interval type that makes up an `IntervalSet`.
+pub type Segment<V> = Interval<OpenOrClosed<V>, OpenOrClosed<V>>;
+
+/// Trait for intervals that can be represented as a single `Segment`.
+pub trait ToSegment {
+    /// The value type of the segment.
+    type Value: PartialOrd;
+
+    /// Convert `self` into a bounded segment.
+    fn to_segment(self) -> Segment<Self::Value>;
+}
+
+macro_rules! impl_to_segment {
+    ($v:ident: $($trait:path),+; $left:ty, $right:ty; |$l:ident, $r:ident| ($lo:expr, $ro:expr)) => {
+        impl<$v: $($trait+)+> ToSegment for Interval<$left, $right> {
+            type Value = $v;
+
+            fn to_segment(self) -> Segment<$v> {
+                let $l = self.left;
+                let $r = self.right;
+
+                Interval::new_unchecked($lo, $ro)
+            }
+        }
+    };
+}
+
+impl_to_segment!(V: PartialOrd; bounds::Closed<V>, bounds::Closed<V>; |l, r| (l.into(), r.into()));
+impl_to_segment!(V: PartialOrd; bounds::Closed<V>, bounds::Open<V>; |l, r| (l.into(), r.into()));
+impl_to_segment!(V: PartialOrd; bounds::Closed<V>, OpenOrClosed<V>; |l, r| (l.into(), r));
+impl_to_segment!(V: PartialOrd; bounds::Open<V>, bounds::Closed<V>; |l, r| (l.into(), r.into()));
+impl_to_segment!(V: PartialOrd; bounds::Open<V>, bounds::Open<V>; |l, r| (l.into(), r.into()));
+impl_to_segment!(V: PartialOrd; bounds::Open<V>, OpenOrClosed<V>; |l, r| (l.into(), r));
+impl_to_segment!(V: PartialOrd; OpenOrClosed<V>, bounds::Closed<V>; |l, r| (l, r.into()));
+impl_to_segment!(V: PartialOrd; OpenOrClosed<V>, bounds::Open<V>; |l, r| (l, r.into()));
+impl_to_segment!(V: PartialOrd; OpenOrClosed<V>, OpenOrClosed<V>; |l, r| (l, r));
+
+// Unbounded integer intervals are truncated to the limits of the type.
+impl_to_segment!(V: num_traits::PrimInt; bounds::NoBound<V>, bounds::Closed<V>; |_l, r| (
+    OpenOrClosed::Closed(V::min_value()), r.into()
+));
+impl_to_segment!(V: num_traits::PrimInt; bounds::NoBound<V>, bounds::Open<V>; |_l, r| (
+    OpenOrClosed::Closed(V::min_value()), r.into()
+));
+impl_to_segment!(V: num_traits::PrimInt; bounds::NoBound<V>, OpenOrClosed<V>; |_l, r| (
+    OpenOrClosed::Closed(V::min_value()), r
+));
+impl_to_segment!(V: num_traits::PrimInt; bounds::Closed<V>, bounds::NoBound<V>; |l, _r| (
+    l.into(), OpenOrClosed::Closed(V::max_value())
+));
+impl_to_segment!(V: num_traits::PrimInt; bounds::Open<V>, bounds::NoBound<V>; |l, _r| (
+    l.into(), OpenOrClosed::Closed(V::max_value())
+));
+impl_to_segment!(V: num_traits::PrimInt; OpenOrClosed<V>, bounds::NoBound<V>; |l, _r| (
+    l, OpenOrClosed::Closed(V::max_value())
+));
+impl_to_segment!(V: num_traits::PrimInt; bounds::NoBound<V>, bounds::NoBound<V>; |_l, _r| (
+    OpenOrClosed::Closed(V::min_value()), OpenOrClosed::Closed(V::max_value())
+));
+
+fn value<V>(bound: &OpenOrClosed<V>) -> &V {
+    match bound {
+        OpenOrClosed::Open(v) | OpenOrClosed::Closed(v) => v,
+    }
+}
+
+// Ordering of left bounds: [x precedes (x.
+fn cmp_left<V: PartialOrd>(x: &OpenOrClosed<V>, y: &OpenOrClosed<V>) -> Ordering {
+    match value(x).partial_cmp(value(y)).unwrap_or(Ordering::Equal) {
+        Ordering::Equal => y.is_closed().cmp(&x.is_closed()),
+        ord => ord,
+    }
+}
+
+// Ordering of right bounds: x) precedes x].
+fn cmp_right<V: PartialOrd>(x: &OpenOrClosed<V>, y: &OpenOrClosed<V>) -> Ordering {
+    match value(x).partial_cmp(value(y)).unwrap_or(Ordering::Equal) {
+        Ordering::Equal => x.is_closed().cmp(&y.is_closed()),
+        ord => ord,
+    }
+}
+
+// Returns true iff a segment ending at `right` overlaps or abuts a segment
+// starting at `left`, such that their union is itself a segment. This is the
+// case when `left` starts no later than the first value after `right`; over
+// the integers, for example, [0, 1] abuts [2, 3].
+fn joins<V: Tighten + Clone>(right: &OpenOrClosed<V>, left: &OpenOrClosed<V>) -> bool {
+    cmp_left(left, &V::tighten_inf(right.clone().flip())) != Ordering::Greater
+}
+
+fn tighten<V: Tighten>(segment: Segment<V>) -> Segment<V> {
+    Interval::new_unchecked(V::tighten_inf(segment.left), V::tighten_sup(segment.right))
+}
+
+fn is_void<V: PartialOrd>(segment: &Segment<V>) -> bool {
+    let (l, r) = (value(&segment.left), value(&segment.right));
+
+    l > r || (l == r && !(segment.left.is_closed() && segment.right.is_closed()))
+}
+
+/// Type representing a finite union of disjoint, bounded intervals.
+///
+/// The segments are kept in canonical form: sorted in ascending order, with
+/// all empty segments removed and all overlapping or adjacent segments merged.
+/// Over discrete values, open bounds are also tightened to closed ones, so
+/// that `[0, 2)` is stored as `[0, 1]`, and `[0, 1] ∪ [2, 3]` as `[0, 3]`.
+/// This means that two sets containing the same values compare equal,
+/// regardless of how they were constructed.
+///
+/// # Examples
+/// ```
+/// # extern crate spaces;
+/// # use spaces::{IntervalSet, Space, ops::{Union, UnionPair}, intervals::Interval};
+/// let a = IntervalSet::from(Interval::closed_unchecked(0.0, 1.0));
+/// let b = IntervalSet::from(UnionPair(
+///     Interval::lorc_unchecked(1.0, 2.0),
+///     Interval::closed_unchecked(3.0, 4.0),
+/// ));
+/// let c = a.union(b);
+///
+/// assert_eq!(c.segments().len(), 2);
+/// assert!(c.contains(&1.5));
+/// assert!(!c.contains(&2.5));
+/// ```
+#[derive(Clone, Debug)]
+pub struct IntervalSet<V: PartialOrd> {
+    segments: Vec<Segment<V>>,
+}
+
+impl<V: PartialOrd + Clone> IntervalSet<V> {
+    /// Construct an empty interval set.
+    pub fn empty() -> Self { IntervalSet { segments: vec![] } }
+
+    /// Return the canonical segments of the set in ascending order.
+    pub fn segments(&self) -> &[Segment<V>] { &self.segments }
+}
+
+impl<V: Tighten + Clone> IntervalSet<V> {
+    /// Construct an interval set from an arbitrary collection of segments.
+    pub fn new<I: IntoIterator<Item = Segment<V>>>(segments: I) -> Self {
+        let mut segments: Vec<_> = segments.into_iter().map(tighten).filter(|s| !is_void(s)).collect();
+
+        segments.sort_by(|x, y| cmp_left(&x.left, &y.left));
+
+        let mut merged: Vec<Segment<V>> = Vec::with_capacity(segments.len());
+
+        for segment in segments {
+            match merged.last_mut() {
+                Some(last) if joins(&last.right, &segment.left) => {
+                    if cmp_right(&segment.right, &last.right) == Ordering::Greater {
+                        last.right = segment.right;
+                    }
+                },
+                _ => merged.push(segment),
+            }
+        }
+
+        IntervalSet { segments: merged }
+    }
+}
+
+impl<V: PartialOrd> PartialEq for IntervalSet<V> {
+    fn eq(&self, rhs: &IntervalSet<V>) -> bool { self.segments == rhs.segments }
+}
+
+impl<L, R> From<Interval<L, R>> for IntervalSet<L::Value>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Tighten + Clone,
+
+    Interval<L, R>: ToSegment<Value = L::Value>,
+{
+    fn from(interval: Interval<L, R>) -> Self { IntervalSet::new(Some(interval.to_segment())) }
+}
+
+impl<V, A, B> From<UnionPair<A, B>> for IntervalSet<V>
+where
+    V: Tighten + Clone,
+    A: Space<Value = V>,
+    B: Space<Value = V>,
+
+    IntervalSet<V>: From<A> + From<B>,
+{
+    fn from(pair: UnionPair<A, B>) -> Self {
+        IntervalSet::from(pair.0).union(IntervalSet::from(pair.1))
+    }
+}
+
+impl<V: PartialOrd + Clone> Space for IntervalSet<V> {
+    type Value = V;
+
+    fn is_empty(&self) -> bool { self.segments.is_empty() }
+
+    fn contains(&self, val: &V) -> bool { self.segments.iter().any(|s| Space::contains(s, val)) }
+}
+
+impl<V: PartialOrd + Clone> OrderedSpace for IntervalSet<V> {
+    fn inf(&self) -> Option<OpenOrClosed<V>> { self.segments.first().map(|s| s.left.clone()) }
+
+    fn sup(&self) -> Option<OpenOrClosed<V>> { self.segments.last().map(|s| s.right.clone()) }
+}
+
+// Returns the number of integers in the closed range [l, r], given l <= r.
+//
+// The gap is taken modulo 2^128, which is exact for any pair of primitive
+// integers, so that segments spanning the full range of V don't overflow.
+fn count<V: num_traits::PrimInt>(l: V, r: V) -> Option<usize> {
+    let gap = match (l.to_i128(), r.to_i128()) {
+        (Some(l), Some(r)) => r.wrapping_sub(l) as u128,
+        _ => r.to_u128()?.wrapping_sub(l.to_u128()?),
+    };
+
+    usize::try_from(gap).ok()?.checked_add(1)
+}
+
+impl<V: num_traits::PrimInt> FiniteSpace for IntervalSet<V> {
+    /// # Panics
+    ///
+    /// Panics if the number of elements overflows a `usize`, as it does for
+    /// the set of all `u64` values on a 64-bit target.
+    fn cardinality(&self) -> usize {
+        // Segments are non-empty, and their bounds closed, in canonical form.
+        self.segments.iter().try_fold(0usize, |acc, s| {
+            count(*value(&s.left), *value(&s.right)).and_then(|n| acc.checked_add(n))
+        }).expect("cardinality overflows usize")
+    }
+}
+
+impl<V: Length + Clone> crate::Measure for IntervalSet<V> {
+    fn measure(&self) -> f64 { self.segments.iter().map(|s| s.measure()).sum() }
+}
+
+impl<V> IterableSpace for IntervalSet<V>
+where
+    V: num_traits::PrimInt,
+
+    RangeInclusive<V>: Iterator<Item = V>,
+{
+    type ElemIter = std::iter::FlatMap<
+        std::vec::IntoIter<Segment<V>>,
+        RangeInclusive<V>,
+        fn(Segment<V>) -> RangeInclusive<V>
+    >;
+
+    fn elements(&self) -> Self::ElemIter {
+        self.segments.clone().into_iter().flat_map(|s| s.elements())
+    }
+}
+
+impl<V: Tighten + Clone> DecidableEmptiness for IntervalSet<V> {
+    fn intersection_is_empty(&self, rhs: &IntervalSet<V>) -> bool {
+        self.clone().intersect(rhs.clone()).is_none()
+    }
+}
+
+impl<V: Tighten + Clone> Union for IntervalSet<V> {
+    type Output = Self;
+
+    fn union(self, rhs: Self) -> Self {
+        IntervalSet::new(self.segments.into_iter().chain(rhs.segments))
+    }
+}
+
+impl<V: Tighten + Clone> Intersection for IntervalSet<V> {
+    type Output = Self;
+
+    fn intersect(self, rhs: Self) -> Option<Self> {
+        let mut segments = vec![];
+        let (mut i, mut j) = (0, 0);
+
+        while i < self.segments.len() && j < rhs.segments.len() {
+            let (x, y) = (&self.segments[i], &rhs.segments[j]);
+
+            let left = match cmp_left(&x.left, &y.left) {
+                Ordering::Less => y.left.clone(),
+                _ => x.left.clone(),
+            };
+            let right = match cmp_right(&x.right, &y.right) {
+                Ordering::Less => { i += 1; x.right.clone() },
+                Ordering::Equal => { i += 1; j += 1; x.right.clone() },
+                Ordering::Greater => { j += 1; y.right.clone() },
+            };
+
+            segments.push(Interval::new_unchecked(left, right));
+        }
+
+        let intersection = IntervalSet::new(segments);
+
+        if intersection.is_empty() { None } else { Some(intersection) }
+    }
+}
+
+impl<V: Tighten + Clone> Difference for IntervalSet<V> {
+    type Output = Self;
+
+    fn difference(self, rhs: Self) -> Option<Self> {
+        let (lo, hi) = match (self.inf(), self.sup()) {
+            (Some(lo), Some(hi)) => (lo, hi),
+            _ => return None,
+        };
+
+        // Gaps between the segments of rhs, restricted to the hull of self:
+        let mut gaps = vec![];
+        let mut left = lo;
+
+        for s in rhs.segments {
+            gaps.push(Interval::new_unchecked(left, s.left.flip()));
+
+            left = s.right.flip();
+        }
+
+        gaps.push(Interval::new_unchecked(left, hi));
+
+        self.intersect(IntervalSet::new(gaps))
+    }
+}
+
+impl<V: Tighten + Clone> Closure for IntervalSet<V> {
+    type Output = Self;
+
+    fn closure(self) -> Self {
+        IntervalSet::new(self.segments.into_iter().map(|s| Interval::new_unchecked(
+            OpenOrClosed::Closed(value(&s.left).clone()),
+            OpenOrClosed::Closed(value(&s.right).clone()),
+        )))
+    }
+}
+
+impl<V: PartialOrd + Clone + std::fmt::Display> Notation for IntervalSet<V> {
+    fn fmt_notation(&self, f: &mut std::fmt::Formatter<'_>, style: Style) -> std::fmt::Result {
+        if self.segments.is_empty() { return notation::fmt_empty(f, style); }
+
+        for (i, s) in self.segments.iter().enumerate() {
+            if i > 0 {
+                match style {
+                    Style::Unicode => write!(f, " \u{222A} ")?,
+                    Style::Latex => write!(f, " \\cup ")?,
+                }
+            }
+
+            s.fmt_notation(f, style)?;
+        }
+
+        Ok(())
+    }
+
+    fn precedence(&self) -> Precedence {
+        if self.segments.len() > 1 { Precedence::Union } else { Precedence::Atom }
+    }
+}
+
+impl<V: PartialOrd + Clone + std::fmt::Display> std::fmt::Display for IntervalSet<V> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.fmt_notation(f, Style::Unicode)
+    }
+}
+
+// Interval sets are serialised as their list of segments. Deserialisation goes
+// through `IntervalSet::new` so that the normal form is restored.
+#[cfg(feature = "serde")]
+impl<V: PartialOrd + serde_crate::Serialize> serde_crate::Serialize for IntervalSet<V> {
+    fn serialize<S: serde_crate::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.segments.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V> serde_crate::Deserialize<'de> for IntervalSet<V>
+where
+    V: Tighten + Clone + serde_crate::Deserialize<'de>,
+{
+    fn deserialize<D: serde_crate::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Vec::<Segment<V>>::deserialize(deserializer).map(IntervalSet::new)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn seg<V: PartialOrd>(l: OpenOrClosed<V>, r: OpenOrClosed<V>) -> Segment<V> {
+        Interval::new_unchecked(l, r)
+    }
+
+    #[test]
+    fn test_normalise() {
+        use OpenOrClosed::{Closed, Open};
+
+        let set = IntervalSet::new(vec![
+            seg(Closed(3.0), Closed(4.0)),
+            seg(Open(1.0), Open(2.0)),
+            seg(Closed(0.0), Closed(1.0)),
+            seg(Open(5.0), Open(5.0)),
+            seg(Open(2.0), Open(3.0)),
+        ]);
+
+        assert_eq!(set.segments(), &[
+            seg(Closed(0.0), Open(2.0)),
+            seg(Open(2.0), Closed(4.0)),
+        ]);
+        assert_eq!(set.inf(), Some(Closed(0.0)));
+        assert_eq!(set.sup(), Some(Closed(4.0)));
+    }
+
+    #[test]
+    fn test_union_pair() {
+        let a = IntervalSet::from(UnionPair(
+            Interval::closed_unchecked(0.0, 1.0),
+            UnionPair(
+                Interval::lcro_unchecked(2.0, 3.0),
+                Interval::open_unchecked(0.5, 2.0),
+            ),
+        ));
+
+        assert_eq!(a, IntervalSet::from(Interval::lcro_unchecked(0.0, 3.0)));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = IntervalSet::from(UnionPair(
+            Interval::closed_unchecked(0, 4),
+            Interval::closed_unchecked(6, 10),
+        ));
+        let b = IntervalSet::from(UnionPair(
+            Interval::open_unchecked(3, 7),
+            Interval::lcro_unchecked(9, 12),
+        ));
+
+        assert_eq!(a.clone().intersect(b.clone()).unwrap(), IntervalSet::new(vec![
+            seg(OpenOrClosed::Open(3), OpenOrClosed::Closed(4)),
+            seg(OpenOrClosed::Closed(6), OpenOrClosed::Open(7)),
+            seg(OpenOrClosed::Closed(9), OpenOrClosed::Closed(10)),
+        ]));
+        assert!(a.intersect(IntervalSet::from(Interval::open_unchecked(4, 6))).is_none());
+    }
+
+    #[test]
+    fn test_difference() {
+        let a = IntervalSet::from(UnionPair(
+            Interval::closed_unchecked(0.0, 4.0),
+            Interval::closed_unchecked(6.0, 10.0),
+        ));
+        let b = IntervalSet::from(UnionPair(
+            Interval::open_unchecked(3.0, 7.0),
+            Interval::lcro_unchecked(9.0, 12.0),
+        ));
+
+        assert_eq!(a.clone().difference(b).unwrap(), IntervalSet::new(vec![
+            seg(OpenOrClosed::Closed(0.0), OpenOrClosed::Closed(3.0)),
+            seg(OpenOrClosed::Closed(7.0), OpenOrClosed::Open(9.0)),
+        ]));
+        assert_eq!(a.clone().difference(IntervalSet::empty()).unwrap(), a);
+        assert!(a.difference(IntervalSet::from(Interval::closed_unchecked(-1.0, 11.0))).is_none());
+    }
+
+    #[test]
+    fn test_closure() {
+        let a = IntervalSet::from(UnionPair(
+            Interval::open_unchecked(0.0, 1.0),
+            Interval::open_unchecked(1.0, 2.0),
+        ));
+
+        assert_eq!(a.segments().len(), 2);
+        assert_eq!(a.closure(), IntervalSet::from(Interval::closed_unchecked(0.0, 2.0)));
+    }
+
+    #[test]
+    fn test_elements() {
+        let a = IntervalSet::from(UnionPair(
+            Interval::lcro_unchecked(0, 3),
+            Interval::lorc_unchecked(5, 7),
+        ));
+
+        assert_eq!(a.cardinality(), 5);
+        assert_eq!(crate::Measure::measure(&a), 5.0);
+        assert_eq!(a.elements().collect::<Vec<_>>(), vec![0, 1, 2, 6, 7]);
+    }
+
+    #[test]
+    fn test_non_zero_integers() {
+        let a = IntervalSet::from(crate::discrete::non_zero_integers::<i8>());
+
+        assert_eq!(a.segments(), &[
+            seg(OpenOrClosed::Closed(-128), OpenOrClosed::Closed(-1)),
+            seg(OpenOrClosed::Closed(1), OpenOrClosed::Closed(127)),
+        ]);
+        assert_eq!(a.cardinality(), 255);
+        assert!(!a.contains(&0));
+    }
+
+    #[test]
+    fn test_discrete_normalise() {
+        use OpenOrClosed::Closed;
+
+        let a = IntervalSet::from(UnionPair(
+            Interval::closed_unchecked(0, 1),
+            Interval::closed_unchecked(2, 3),
+        ));
+
+        assert_eq!(a.segments(), &[seg(Closed(0), Closed(3))]);
+        assert_eq!(IntervalSet::from(Interval::lcro_unchecked(0, 2)), IntervalSet::from(Interval::closed_unchecked(0, 1)));
+        assert_eq!(IntervalSet::from(Interval::open_unchecked(0, 3)).segments(), &[seg(Closed(1), Closed(2))]);
+        assert!(IntervalSet::from(Interval::open_unchecked(0, 1)).is_empty());
+
+        // Real-valued segments are only merged if they overlap or touch.
+        let b = IntervalSet::from(UnionPair(
+            Interval::closed_unchecked(0.0, 1.0),
+            Interval::closed_unchecked(2.0, 3.0),
+        ));
+
+        assert_eq!(b.segments().len(), 2);
+    }
+
+    #[test]
+    fn test_cardinality_full_range() {
+        let a = IntervalSet::from(crate::discrete::integers::<i32>());
+
+        assert_eq!(a.cardinality(), 1usize << 32);
+        assert_eq!(IntervalSet::from(Interval::closed_unchecked(u128::MAX - 4, u128::MAX)).cardinality(), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "cardinality overflows usize")]
+    fn test_cardinality_overflow() {
+        IntervalSet::from(Interval::closed_unchecked(0u64, u64::MAX)).cardinality();
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let a = IntervalSet::new(vec![
+            seg(OpenOrClosed::Closed(2.0), OpenOrClosed::Open(3.0)),
+            seg(OpenOrClosed::Closed(0.0), OpenOrClosed::Closed(1.0)),
+        ]);
+        let json = serde_json::to_string(&a).unwrap();
+
+        assert_eq!(json, r#"[{"left":{"Closed":0.0},"right":{"Closed":1.0}},{"left":{"Closed":2.0},"right":{"Open":3.0}}]"#);
+        assert_eq!(serde_json::from_str::<IntervalSet<f64>>(&json).unwrap(), a);
+
+        // Overlapping segments are merged on the way in.
+        let b: IntervalSet<f64> = serde_json::from_str(
+            r#"[{"left":{"Closed":0.0},"right":{"Closed":2.0}},{"left":{"Open":1.0},"right":{"Open":3.0}}]"#
+        ).unwrap();
+
+        assert_eq!(b.segments(), &[seg(OpenOrClosed::Closed(0.0), OpenOrClosed::Open(3.0))]);
+    }
+}
@@ -3,21 +3,117 @@
 //! `spaces` provides set/space primitives to be used for defining properties of
 //! machine learning problems. Traits such as `Space`, and it's derivatives, may
 //! be used to define state/action spaces, for example.
+//!
+//! # Serialisation
+//!
+//! With the `serde` feature enabled, every space type in this crate implements
+//! `Serialize` and `Deserialize`. The JSON shapes are as follows:
+//!
+//! - Intervals are objects `{"left": .., "right": ..}`. `Open` and `Closed`
+//!   bounds are encoded as their bare value, `NoBound` as `null`, and
+//!   `OpenOrClosed` bounds as `{"Open": v}` or `{"Closed": v}`. The kind of
+//!   an `Open` or `Closed` bound is carried by the static interval type
+//!   alone, so it must be known when deserialising.
+//! - `partitions::Uniform` is `{"size": n, "left": a, "right": b}`;
+//!   `partitions::Declarative` can be encoded as the array of its bounds via
+//!   [`serde_declarative`].
+//! - `IntervalSet` is the array of its segments in normal form.
+//! - Arrays, tuples, `Vec` and `Option` use serde's standard encoding.
+//! - `Homogeneous` is `{"space": .., "len": n}`.
+//! - `TileCoding` is `{"space": .., "n_tilings": n, "tiles": [..], "memory": m}`
+//!   with an optional `memory` size; deserialising validates the parameters.
+//! - `UnionPair`, `IntersectionPair` and `DifferencePair` are two-element
+//!   arrays `[a, b]`, while `ComplementOf` is transparent over its inner space.
+//!   Their operands use the encodings above unchanged, so the interval bounds
+//!   in the example below are untagged too.
+//! - `OpenBounds`, `dynamic::DynValue` and `dynamic::SpaceDescriptor` use
+//!   serde's externally tagged enum encoding, e.g. `{"Discrete": 5}`.
+//!
+//! ```
+//! # extern crate spaces;
+//! # extern crate serde_json;
+//! # #[cfg(feature = "serde")]
+//! # fn main() {
+//! use spaces::{IntervalSet, intervals::Interval, ops::UnionPair};
+//!
+//! let space = UnionPair(Interval::closed_unchecked(0.0, 1.0), Interval::open_unchecked(2.0, 3.0));
+//! let json = serde_json::to_string(&space).unwrap();
+//!
+//! assert_eq!(json, r#"[{"left":0.0,"right":1.0},{"left":2.0,"right":3.0}]"#);
+//!
+//! let set: IntervalSet<f64> = IntervalSet::from(space);
+//! let json = serde_json::to_string(&set).unwrap();
+//!
+//! assert_eq!(serde_json::from_str::<IntervalSet<f64>>(&json).unwrap(), set);
+//! # }
+//! # #[cfg(not(feature = "serde"))]
+//! # fn main() {}
+//! ```
 extern crate itertools;
 extern crate num_traits;
 
+#[cfg(feature = "rand")]
+extern crate rand;
+
+#[cfg(feature = "json-schema")]
+extern crate serde_json;
+
+#[cfg_attr(feature = "serde", macro_use)]
+#[cfg(feature = "serde")]
+extern crate serde_crate;
+
 pub mod discrete;
+pub mod dynamic;
+pub mod notation;
+pub use self::notation::{parse, Notation};
+#[cfg(feature = "gym")]
+pub mod gym;
+pub mod mappings;
+pub mod project;
 pub mod real;
+pub mod validate;
 
 pub extern crate intervals;
 
+#[cfg(feature = "derive")]
+extern crate spaces_derive;
+
+/// Derive a companion space type for a struct or enum.
+///
+/// See the `spaces-derive` crate for details.
+#[cfg(feature = "derive")]
+pub use spaces_derive::Space;
+
 use intervals::bounds::OpenOrClosed;
 
 mod arrays;
+pub use self::arrays::ArrayIter;
+
 mod interval;
 mod partitions;
+#[cfg(feature = "serde")]
+pub use self::partitions::serde_declarative;
 mod option;
+
 mod tuples;
+pub use self::tuples::ProductIter;
+
+mod interval_set;
+pub use self::interval_set::{IntervalSet, Segment, ToSegment};
+
+mod vectors;
+pub use self::vectors::Homogeneous;
+
+mod encode;
+pub use self::encode::Encode;
+
+mod tile_coding;
+pub use self::tile_coding::{TileCoding, Tileable};
+
+#[cfg(feature = "json-schema")]
+mod schema;
+#[cfg(feature = "json-schema")]
+pub use self::schema::ToJsonSchema;
 
 ///////////////////////////////////////////////////////////////////////////
 // Core Definitions
@@ -75,6 +171,57 @@ pub trait FiniteSpace: Space {
     fn cardinality(&self) -> usize;
 }
 
+/// Trait for finite spaces whose elements can be mapped bijectively onto the
+/// indices `0..cardinality()`.
+///
+/// Indices follow the order of `IterableSpace::elements`, where one exists;
+/// products use a mixed-radix encoding in which the last component varies
+/// fastest.
+///
+/// ```
+/// # extern crate spaces;
+/// # use spaces::{IndexedSpace, discrete, intervals::Interval};
+/// let space = (discrete::binary(), Interval::lcro_unchecked(0usize, 3));
+///
+/// assert_eq!(space.index_of(&(true, 1)), Some(4));
+/// assert_eq!(space.from_index(4), Some((true, 1)));
+/// assert_eq!(space.from_index(6), None);
+/// ```
+pub trait IndexedSpace: FiniteSpace {
+    /// Return the index of `val`, or `None` if it is not contained in the space.
+    fn index_of(&self, val: &Self::Value) -> Option<usize>;
+
+    /// Return the element at `index`, or `None` if `index >= cardinality()`.
+    #[allow(clippy::wrong_self_convention)]
+    fn from_index(&self, index: usize) -> Option<Self::Value>;
+}
+
+/// Trait for spaces with a well-defined size.
+///
+/// Continuous spaces are measured by the Lebesgue measure (i.e. length, area,
+/// volume, ...) and discrete spaces by the counting measure.
+///
+/// ```
+/// # extern crate spaces;
+/// # use spaces::{Measure, real, intervals::Interval};
+/// assert_eq!(Interval::closed_unchecked(0.0, 2.5).measure(), 2.5);
+/// assert_eq!(Interval::closed_unchecked(0, 2).measure(), 3.0);
+/// assert_eq!([Interval::closed_unchecked(0.0, 2.0); 3].measure(), 8.0);
+/// assert_eq!(real::reals::<f64>().measure(), f64::INFINITY);
+/// ```
+pub trait Measure: Space {
+    /// Return the measure of the space, or `f64::INFINITY` if unbounded.
+    fn measure(&self) -> f64;
+}
+
+/// Multiply the measures of the factors of a product space.
+///
+/// A null factor makes the whole product null, even alongside unbounded
+/// factors, so the result is never `0 * INF = NaN`.
+pub(crate) fn product_measure<I: IntoIterator<Item = f64>>(measures: I) -> f64 {
+    measures.into_iter().fold(1.0, |acc, m| if acc == 0.0 || m == 0.0 { 0.0 } else { acc * m })
+}
+
 /// Trait for `Space` types that have an associated value iterator.
 pub trait IterableSpace: Space {
     /// The associated iterator type.
@@ -84,6 +231,69 @@ pub trait IterableSpace: Space {
     fn elements(&self) -> Self::ElemIter;
 }
 
+/// Trait for spaces onto which arbitrary values can be projected.
+///
+/// ```
+/// # extern crate spaces;
+/// # use spaces::{Project, intervals::Interval};
+/// let space = Interval::lcro_unchecked(0, 10);
+///
+/// assert_eq!(space.project(-5), 0);
+/// assert_eq!(space.project(5), 5);
+/// assert_eq!(space.project(15), 9);
+/// ```
+pub trait Project: Space {
+    /// Map `val` to the nearest element of the space, resolving open bounds
+    /// using the given strategy.
+    fn project_with(&self, val: Self::Value, strategy: project::OpenBounds) -> Self::Value;
+
+    /// Map `val` to the nearest element of the space, resolving open bounds
+    /// to the next representable value.
+    fn project(&self, val: Self::Value) -> Self::Value {
+        self.project_with(val, project::OpenBounds::NextRepresentable)
+    }
+}
+
+/// Trait for spaces that can explain why a value is not contained within them.
+///
+/// ```
+/// # extern crate spaces;
+/// # use spaces::{Validate, intervals::Interval};
+/// let space = (Interval::closed_unchecked(0, 5), [Interval::open_unchecked(0.0, 1.0); 3]);
+/// let err = space.validate(&(2, [0.5, 1.0, 0.5])).unwrap_err();
+///
+/// assert_eq!(err.to_string(), "at .1[1]: value 1.0 lies above the open upper bound 1.0");
+/// ```
+pub trait Validate: Space {
+    /// Return `Ok` iff `val` is contained within the space, and otherwise
+    /// describe the first offending component.
+    fn validate(&self, val: &Self::Value) -> Result<(), validate::ContainmentError>;
+}
+
+/// Trait for spaces from which values can be drawn at random.
+///
+/// Values are sampled uniformly with respect to the natural measure of the
+/// space. Unbounded spaces, such as `real::Reals`, do not implement this trait.
+///
+/// ```
+/// # extern crate rand;
+/// # extern crate spaces;
+/// # use spaces::{Sample, Space, intervals::Interval};
+/// let space = [Interval::closed_unchecked(0.0, 1.0); 2];
+/// let value = space.sample(&mut rand::thread_rng());
+///
+/// assert!(space.contains(&value));
+/// ```
+#[cfg(feature = "rand")]
+pub trait Sample: Space {
+    /// Draw a random value from the space.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the space is empty.
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Self::Value;
+}
+
 ///////////////////////////////////////////////////////////////////////////
 // Set Operations
 ///////////////////////////////////////////////////////////////////////////
@@ -95,6 +305,10 @@ pub mod ops;
 mod prelude {
     pub use super::{
         ops::{Union, Intersection, Closure},
-        FiniteSpace, OrderedSpace, Space, IterableSpace,
+        Encode, FiniteSpace, IndexedSpace, OrderedSpace, Space, IterableSpace, Measure, Project,
+        Validate,
     };
+
+    #[cfg(feature = "rand")]
+    pub use super::Sample;
 }
@@ -0,0 +1,500 @@
+//! Module for maps between the values of different spaces.
+//!
+//! ```
+//! # extern crate spaces;
+//! # use spaces::{intervals::Interval, mappings::{Mapping, Normalisable, Target}};
+//! let space = [Interval::closed_unchecked(0.0, 10.0), Interval::closed_unchecked(-1.0, 1.0)];
+//! let normalise = space.normaliser(Target::Unit).unwrap();
+//! let denormalise = space.denormaliser(Target::Unit).unwrap();
+//!
+//! assert_eq!(normalise.apply([2.5, 0.0]), [0.25, 0.5]);
+//! assert_eq!(denormalise.apply([0.25, 0.5]), [2.5, 0.0]);
+//! ```
+use crate::{
+    IndexedSpace, OrderedSpace, Space,
+    intervals::{Interval, bounds, partitions::Uniform},
+};
+use num_traits::Float;
+use std::fmt;
+
+/// Trait for maps from one set of values to another.
+pub trait Mapping {
+    /// The type of values accepted by the map.
+    type Input;
+
+    /// The type of values produced by the map.
+    type Output;
+
+    /// Apply the map to `x`.
+    fn apply(&self, x: Self::Input) -> Self::Output;
+}
+
+/// Interval onto which a bounded space is normalised.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Target {
+    /// The unit interval, `[0, 1]`.
+    Unit,
+
+    /// The symmetric interval, `[-1, 1]`.
+    Symmetric,
+}
+
+impl Target {
+    fn limits<V: Float>(self) -> (V, V) {
+        match self {
+            Target::Unit => (V::zero(), V::one()),
+            Target::Symmetric => (-V::one(), V::one()),
+        }
+    }
+}
+
+/// Error type for mappings that cannot be constructed from a space.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum MappingError {
+    /// The space is not bounded above and below.
+    Unbounded,
+
+    /// The infimum of the space is not less than its supremum.
+    Degenerate,
+
+    /// A discretisation was requested with zero bins.
+    NoBins,
+
+    /// A per-dimension parameter does not match the dimensionality of the space.
+    Dimensions {
+        /// The number of dimensions of the space.
+        expected: usize,
+
+        /// The number of dimensions provided.
+        found: usize,
+    },
+}
+
+impl fmt::Display for MappingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MappingError::Unbounded => f.write_str("space must be bounded above and below"),
+            MappingError::Degenerate => f.write_str("space must have positive width"),
+            MappingError::NoBins => f.write_str("discretisation must have at least one bin"),
+            MappingError::Dimensions { expected, found } => {
+                write!(f, "expected parameters for {} dimensions, found {}", expected, found)
+            },
+        }
+    }
+}
+
+impl std::error::Error for MappingError {}
+
+pub(crate) fn limits<S>(space: &S) -> Result<(S::Value, S::Value), MappingError>
+where
+    S: OrderedSpace,
+    S::Value: Float,
+{
+    if !space.is_bounded() { return Err(MappingError::Unbounded); }
+
+    let inf = space.inf().unwrap().unwrap();
+    let sup = space.sup().unwrap().unwrap();
+
+    if inf < sup { Ok((inf, sup)) } else { Err(MappingError::Degenerate) }
+}
+
+///////////////////////////////////////////////////////////////////////////
+// Affine Maps
+///////////////////////////////////////////////////////////////////////////
+/// Affine map from a bounded interval onto a normalised `Target`.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Normalise<V> {
+    inf: V,
+    sup: V,
+    target: Target,
+}
+
+impl<V: Float> Normalise<V> {
+    /// Construct the map sending the limits of `space` onto those of `target`.
+    ///
+    /// Returns an error if `space` is unbounded or has zero width.
+    pub fn new<S: OrderedSpace<Value = V>>(space: &S, target: Target) -> Result<Normalise<V>, MappingError> {
+        let (inf, sup) = limits(space)?;
+
+        Ok(Normalise { inf, sup, target, })
+    }
+
+    /// Return the inverse of this map.
+    pub fn inverse(&self) -> Denormalise<V> {
+        Denormalise { inf: self.inf, sup: self.sup, target: self.target, }
+    }
+}
+
+impl<V: Float> Mapping for Normalise<V> {
+    type Input = V;
+    type Output = V;
+
+    fn apply(&self, x: V) -> V {
+        let (a, b) = self.target.limits::<V>();
+
+        a + (x - self.inf) / (self.sup - self.inf) * (b - a)
+    }
+}
+
+/// Affine map from a normalised `Target` back onto a bounded interval.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub struct Denormalise<V> {
+    inf: V,
+    sup: V,
+    target: Target,
+}
+
+impl<V: Float> Denormalise<V> {
+    /// Construct the map sending the limits of `target` onto those of `space`.
+    ///
+    /// Returns an error if `space` is unbounded or has zero width.
+    pub fn new<S: OrderedSpace<Value = V>>(space: &S, target: Target) -> Result<Denormalise<V>, MappingError> {
+        Normalise::new(space, target).map(|n| n.inverse())
+    }
+
+    /// Return the inverse of this map.
+    pub fn inverse(&self) -> Normalise<V> {
+        Normalise { inf: self.inf, sup: self.sup, target: self.target, }
+    }
+}
+
+impl<V: Float> Mapping for Denormalise<V> {
+    type Input = V;
+    type Output = V;
+
+    fn apply(&self, x: V) -> V {
+        let (a, b) = self.target.limits::<V>();
+
+        self.inf + (x - a) / (b - a) * (self.sup - self.inf)
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+// Normalisable Spaces
+///////////////////////////////////////////////////////////////////////////
+/// Trait for spaces that admit normalisation onto a `Target`.
+pub trait Normalisable: Space {
+    /// The map from values of the space onto the target.
+    type Normaliser: Mapping<Input = Self::Value>;
+
+    /// The map from the target back onto values of the space.
+    type Denormaliser: Mapping<Output = Self::Value>;
+
+    /// Construct the normalising map for this space.
+    fn normaliser(&self, target: Target) -> Result<Self::Normaliser, MappingError>;
+
+    /// Construct the denormalising map for this space.
+    fn denormaliser(&self, target: Target) -> Result<Self::Denormaliser, MappingError>;
+}
+
+impl<L, R> Normalisable for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Float,
+{
+    type Normaliser = Normalise<L::Value>;
+    type Denormaliser = Denormalise<L::Value>;
+
+    fn normaliser(&self, target: Target) -> Result<Normalise<L::Value>, MappingError> {
+        Normalise::new(self, target)
+    }
+
+    fn denormaliser(&self, target: Target) -> Result<Denormalise<L::Value>, MappingError> {
+        Denormalise::new(self, target)
+    }
+}
+
+fn try_array<const N: usize, T>(
+    f: impl FnMut(usize) -> Result<T, MappingError>,
+) -> Result<[T; N], MappingError> {
+    let items: [Result<T, MappingError>; N] = std::array::from_fn(f);
+
+    if let Some(Err(e)) = items.iter().find(|r| r.is_err()) { return Err(*e); }
+
+    Ok(items.map(|r| r.ok().unwrap()))
+}
+
+impl<const N: usize, D: Normalisable> Normalisable for [D; N] {
+    type Normaliser = [D::Normaliser; N];
+    type Denormaliser = [D::Denormaliser; N];
+
+    fn normaliser(&self, target: Target) -> Result<Self::Normaliser, MappingError> {
+        try_array(|i| self[i].normaliser(target))
+    }
+
+    fn denormaliser(&self, target: Target) -> Result<Self::Denormaliser, MappingError> {
+        try_array(|i| self[i].denormaliser(target))
+    }
+}
+
+impl<const N: usize, M: Mapping> Mapping for [M; N] {
+    type Input = [M::Input; N];
+    type Output = [M::Output; N];
+
+    fn apply(&self, x: Self::Input) -> Self::Output {
+        let mut x = IntoIterator::into_iter(x);
+
+        std::array::from_fn(|i| self[i].apply(x.next().unwrap()))
+    }
+}
+
+macro_rules! impl_tuple {
+    ($(($tp:ident, $mp:ident)::$i:tt),+) => {
+        impl<$($tp: Normalisable),+> Normalisable for ($($tp),+) {
+            type Normaliser = ($($tp::Normaliser),+);
+            type Denormaliser = ($($tp::Denormaliser),+);
+
+            fn normaliser(&self, target: Target) -> Result<Self::Normaliser, MappingError> {
+                Ok(($(self.$i.normaliser(target)?),+))
+            }
+
+            fn denormaliser(&self, target: Target) -> Result<Self::Denormaliser, MappingError> {
+                Ok(($(self.$i.denormaliser(target)?),+))
+            }
+        }
+
+        impl<$($mp: Mapping),+> Mapping for ($($mp),+) {
+            type Input = ($($mp::Input),+);
+            type Output = ($($mp::Output),+);
+
+            fn apply(&self, x: Self::Input) -> Self::Output {
+                ($(self.$i.apply(x.$i)),+)
+            }
+        }
+    }
+}
+
+impl_tuple!((D1, M1)::0, (D2, M2)::1);
+impl_tuple!((D1, M1)::0, (D2, M2)::1, (D3, M3)::2);
+impl_tuple!((D1, M1)::0, (D2, M2)::1, (D3, M3)::2, (D4, M4)::3);
+impl_tuple!((D1, M1)::0, (D2, M2)::1, (D3, M3)::2, (D4, M4)::3, (D5, M5)::4);
+impl_tuple!((D1, M1)::0, (D2, M2)::1, (D3, M3)::2, (D4, M4)::3, (D5, M5)::4, (D6, M6)::5);
+impl_tuple!((D1, M1)::0, (D2, M2)::1, (D3, M3)::2, (D4, M4)::3, (D5, M5)::4, (D6, M6)::5, (D7, M7)::6);
+impl_tuple!((D1, M1)::0, (D2, M2)::1, (D3, M3)::2, (D4, M4)::3, (D5, M5)::4, (D6, M6)::5, (D7, M7)::6, (D8, M8)::7);
+impl_tuple!((D1, M1)::0, (D2, M2)::1, (D3, M3)::2, (D4, M4)::3, (D5, M5)::4, (D6, M6)::5, (D7, M7)::6, (D8, M8)::7, (D9, M9)::8);
+impl_tuple!((D1, M1)::0, (D2, M2)::1, (D3, M3)::2, (D4, M4)::3, (D5, M5)::4, (D6, M6)::5, (D7, M7)::6, (D8, M8)::7, (D9, M9)::8, (D10, M10)::9);
+impl_tuple!((D1, M1)::0, (D2, M2)::1, (D3, M3)::2, (D4, M4)::3, (D5, M5)::4, (D6, M6)::5, (D7, M7)::6, (D8, M8)::7, (D9, M9)::8, (D10, M10)::9, (D11, M11)::10);
+impl_tuple!((D1, M1)::0, (D2, M2)::1, (D3, M3)::2, (D4, M4)::3, (D5, M5)::4, (D6, M6)::5, (D7, M7)::6, (D8, M8)::7, (D9, M9)::8, (D10, M10)::9, (D11, M11)::10, (D12, M12)::11);
+
+///////////////////////////////////////////////////////////////////////////
+// Discretisation
+///////////////////////////////////////////////////////////////////////////
+/// Trait for finite spaces of bins that tile a continuous space.
+///
+/// Bins are numbered consistently with `IndexedSpace`, so grids over boxes
+/// use a mixed-radix encoding in which the last dimension varies fastest.
+pub trait Bins: IndexedSpace {
+    /// The type of points in the continuous space.
+    type Point;
+
+    /// The type describing the extent of a single bin.
+    type Bounds;
+
+    /// Return the bin containing `x`.
+    ///
+    /// Points beyond the tiled region are assigned to the nearest edge bin.
+    fn to_bin(&self, x: &Self::Point) -> usize;
+
+    /// Return the centre of the given bin, or `None` if it does not exist.
+    fn bin_centre(&self, bin: usize) -> Option<Self::Point>;
+
+    /// Return the extent of the given bin, or `None` if it does not exist.
+    fn bin_bounds(&self, bin: usize) -> Option<Self::Bounds>;
+}
+
+impl<V: Float> Bins for Uniform<V> {
+    type Point = V;
+    type Bounds = Interval<bounds::Closed<V>, bounds::OpenOrClosed<V>>;
+
+    fn to_bin(&self, x: &V) -> usize {
+        let last = self.size.saturating_sub(1);
+        let k = ((*x - self.left) / self.partition_width()).floor().max(V::zero());
+
+        k.to_usize().map_or(last, |k| k.min(last))
+    }
+
+    fn bin_centre(&self, bin: usize) -> Option<V> {
+        let two = V::one() + V::one();
+
+        self.bin_bounds(bin).map(|b| (b.left.0 + b.right.unwrap()) / two)
+    }
+
+    fn bin_bounds(&self, bin: usize) -> Option<Self::Bounds> {
+        if bin >= self.size { return None; }
+
+        let width = self.partition_width();
+        let left = self.left + width * V::from(bin).unwrap();
+
+        Some(Interval {
+            left: bounds::Closed(left),
+            right: if bin == self.size - 1 {
+                bounds::OpenOrClosed::Closed(self.right)
+            } else {
+                bounds::OpenOrClosed::Open(left + width)
+            },
+        })
+    }
+}
+
+impl<const N: usize, B: Bins> Bins for [B; N] {
+    type Point = [B::Point; N];
+    type Bounds = [B::Bounds; N];
+
+    fn to_bin(&self, x: &Self::Point) -> usize {
+        self.iter().zip(x.iter()).fold(0, |acc, (b, x)| acc * b.cardinality() + b.to_bin(x))
+    }
+
+    fn bin_centre(&self, bin: usize) -> Option<Self::Point> {
+        let cell = self.from_index(bin)?;
+        let centres: [Option<B::Point>; N] = std::array::from_fn(|i| {
+            self[i].index_of(&cell[i]).and_then(|k| self[i].bin_centre(k))
+        });
+
+        if centres.iter().any(|c| c.is_none()) { return None; }
+
+        Some(centres.map(|c| c.unwrap()))
+    }
+
+    fn bin_bounds(&self, bin: usize) -> Option<Self::Bounds> {
+        let cell = self.from_index(bin)?;
+        let bounds: [Option<B::Bounds>; N] = std::array::from_fn(|i| {
+            self[i].index_of(&cell[i]).and_then(|k| self[i].bin_bounds(k))
+        });
+
+        if bounds.iter().any(|b| b.is_none()) { return None; }
+
+        Some(bounds.map(|b| b.unwrap()))
+    }
+}
+
+/// Trait for bounded continuous spaces that can be binned into a finite grid.
+///
+/// ```
+/// # extern crate spaces;
+/// # use spaces::{FiniteSpace, intervals::Interval, mappings::{Bins, Discretise}};
+/// let space = [Interval::closed_unchecked(0.0, 1.0), Interval::closed_unchecked(-1.0, 1.0)];
+/// let grid = space.discretise([4, 2]).unwrap();
+///
+/// assert_eq!(grid.cardinality(), 8);
+/// assert_eq!(grid.to_bin(&[0.3, 0.5]), 3);
+/// assert_eq!(grid.bin_centre(3), Some([0.375, 0.5]));
+/// ```
+pub trait Discretise: Space {
+    /// The number of bins to use, per dimension.
+    type Resolution;
+
+    /// The finite space of bins.
+    type Output: Bins<Point = Self::Value>;
+
+    /// Partition the space into uniform bins at the given resolution.
+    ///
+    /// Returns an error if the space is unbounded or degenerate, or if any
+    /// dimension is given zero bins.
+    fn discretise(&self, resolution: Self::Resolution) -> Result<Self::Output, MappingError>;
+}
+
+impl<L, R> Discretise for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Float,
+{
+    type Resolution = usize;
+    type Output = Uniform<L::Value>;
+
+    fn discretise(&self, resolution: usize) -> Result<Uniform<L::Value>, MappingError> {
+        if resolution == 0 { return Err(MappingError::NoBins); }
+
+        let (left, right) = limits(self)?;
+
+        Ok(Uniform { size: resolution, left, right, })
+    }
+}
+
+impl<const N: usize, D> Discretise for [D; N]
+where
+    D: Discretise,
+    D::Resolution: Copy,
+{
+    type Resolution = [D::Resolution; N];
+    type Output = [D::Output; N];
+
+    fn discretise(&self, resolution: Self::Resolution) -> Result<Self::Output, MappingError> {
+        try_array(|i| self[i].discretise(resolution[i]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::FiniteSpace;
+
+    #[test]
+    fn test_interval() {
+        let space = Interval::closed_unchecked(-2.0, 6.0);
+        let n = space.normaliser(Target::Unit).unwrap();
+        let s = space.normaliser(Target::Symmetric).unwrap();
+
+        assert_eq!(n.apply(-2.0), 0.0);
+        assert_eq!(n.apply(4.0), 0.75);
+        assert_eq!(s.apply(2.0), 0.0);
+        assert_eq!(s.apply(6.0), 1.0);
+        assert_eq!(s.inverse().apply(-0.5), 0.0);
+        assert_eq!(space.denormaliser(Target::Unit).unwrap().apply(0.75), 4.0);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(Interval::left_closed(0.0).normaliser(Target::Unit), Err(MappingError::Unbounded));
+        assert_eq!(Interval::degenerate(1.0f32).normaliser(Target::Unit), Err(MappingError::Degenerate));
+        assert_eq!(
+            [Interval::closed_unchecked(0.0, 1.0), Interval::closed_unchecked(1.0, 1.0)]
+                .denormaliser(Target::Symmetric),
+            Err(MappingError::Degenerate)
+        );
+    }
+
+    #[test]
+    fn test_tuple() {
+        let space = (Interval::closed_unchecked(0.0f32, 4.0), Interval::lcro_unchecked(-1.0f64, 1.0));
+        let n = space.normaliser(Target::Symmetric).unwrap();
+        let d = space.denormaliser(Target::Symmetric).unwrap();
+
+        assert_eq!(n.apply((1.0, 0.5)), (-0.5, 0.5));
+        assert_eq!(d.apply(n.apply((3.0, -0.25))), (3.0, -0.25));
+    }
+
+    #[test]
+    fn test_uniform_bins() {
+        let bins = Interval::closed_unchecked(0.0, 2.0).discretise(4).unwrap();
+
+        assert_eq!(bins.to_bin(&0.0), 0);
+        assert_eq!(bins.to_bin(&0.6), 1);
+        assert_eq!(bins.to_bin(&2.0), 3);
+        assert_eq!(bins.to_bin(&-5.0), 0);
+        assert_eq!(bins.to_bin(&1e30), 3);
+        assert_eq!(bins.bin_centre(2), Some(1.25));
+        assert_eq!(bins.bin_centre(4), None);
+        assert_eq!(bins.bin_bounds(1), Some(Interval {
+            left: bounds::Closed(0.5),
+            right: bounds::OpenOrClosed::Open(1.0),
+        }));
+        assert_eq!(bins.bin_bounds(3).unwrap().right, bounds::OpenOrClosed::Closed(2.0));
+
+        assert_eq!(Interval::closed_unchecked(0.0, 1.0).discretise(0), Err(MappingError::NoBins));
+        assert_eq!(Interval::left_closed(0.0).discretise(2), Err(MappingError::Unbounded));
+    }
+
+    #[test]
+    fn test_grid_bins() {
+        let grid = [Interval::closed_unchecked(0.0, 3.0); 2].discretise([3, 2]).unwrap();
+
+        assert_eq!(grid.cardinality(), 6);
+        assert!((0..6).all(|k| grid.to_bin(&grid.bin_centre(k).unwrap()) == k));
+        assert_eq!(grid.to_bin(&[2.5, 0.0]), 4);
+        assert_eq!(grid.bin_bounds(6), None);
+    }
+}
@@ -0,0 +1,1102 @@
+//! Module for parsing and rendering spaces in mathematical notation.
+//!
+//! Spaces are rendered via the `Notation` trait, either as unicode text or as
+//! LaTeX, and runtime-typed spaces may be parsed back with `parse`.
+//!
+//! The grammar, from lowest to highest precedence, is as follows:
+//!
+//! | Syntax                       | Meaning          | ASCII         |
+//! |------------------------------|------------------|---------------|
+//! | `A ∪ B`                      | union            | `A U B`       |
+//! | `A ∖ B`                      | difference       | `A \ B`       |
+//! | `A ∩ B`                      | intersection     | `A & B`       |
+//! | `A × B × ...`                | tuple product    | `A x B`, `A * B` |
+//! | `A^n`, `Aⁿ`                  | homogeneous power | `A^n`        |
+//! | `[a, b)`, `(-∞, b]`, ...     | interval         | `(-inf, b]`   |
+//! | `{a..b}`, `{-∞..b}`, ...     | integer range    | `{a..inf}`    |
+//! | `{x, y, ...}`                | enumeration      |               |
+//! | `ℝ`, `ℤ`, `ℕ`, `𝔹`, `∅`       | standard sets    | `R`, `Z`, `N`, `B`, `{}` |
+//!
+//! Parentheses may also be used for grouping. Bracketed intervals are always
+//! real-valued, even if their bounds are written as integers; integer-valued
+//! intervals are written as ranges instead, and ranges may be infinite at
+//! either end.
+//!
+//! ```
+//! # extern crate spaces;
+//! # use spaces::dynamic::{DynSpace, DynValue, SpaceDescriptor};
+//! let space: SpaceDescriptor = "(0, 1.0] x {true, false}".parse().unwrap();
+//!
+//! assert!(space.contains(&DynValue::from((0.5, true))));
+//! assert!(!space.contains(&DynValue::from((0.0, true))));
+//!
+//! let err = spaces::parse("[0, 1) U (2, +inf").unwrap_err();
+//!
+//! assert_eq!(err.span, 17..17);
+//! ```
+use crate::dynamic::{DynValue, ScalarKind, SpaceDescriptor};
+use crate::intervals::bounds::OpenOrClosed;
+use std::{fmt, ops::Range, str::FromStr};
+
+///////////////////////////////////////////////////////////////////////////
+// Errors
+///////////////////////////////////////////////////////////////////////////
+/// The kind of error encountered while parsing a space.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ParseErrorKind {
+    /// A character that does not begin any token.
+    UnexpectedChar(char),
+
+    /// A token other than the one(s) expected.
+    UnexpectedToken { found: String, expected: &'static str },
+
+    /// The input ended while more was expected.
+    UnexpectedEnd { expected: &'static str },
+
+    /// A numeric literal that could not be represented.
+    InvalidNumber,
+
+    /// An infinite interval bound adjacent to a square bracket.
+    ClosedInfiniteBound,
+
+    /// An infinite bound of the wrong sign, e.g. `(+inf, 0]`.
+    MisplacedInfinity,
+
+    /// A set enumeration mixing booleans and numbers.
+    MixedEnumeration,
+
+    /// An interval or range whose lower bound exceeds its upper bound.
+    ReversedBounds,
+}
+
+/// Error type for malformed space notation.
+///
+/// The `span` gives the byte range of the offending input.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct ParseError {
+    /// The kind of error.
+    pub kind: ParseErrorKind,
+
+    /// The byte range of the input at which the error occurred.
+    pub span: Range<usize>,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            ParseErrorKind::UnexpectedChar(c) => write!(f, "unexpected character '{}'", c),
+            ParseErrorKind::UnexpectedToken { found, expected } => {
+                write!(f, "expected {}, found '{}'", expected, found)
+            },
+            ParseErrorKind::UnexpectedEnd { expected } => {
+                write!(f, "expected {}, found end of input", expected)
+            },
+            ParseErrorKind::InvalidNumber => write!(f, "invalid number"),
+            ParseErrorKind::ClosedInfiniteBound => write!(f, "infinite bounds must be open"),
+            ParseErrorKind::MisplacedInfinity => write!(f, "infinite bound has the wrong sign"),
+            ParseErrorKind::ReversedBounds => write!(f, "lower bound exceeds upper bound"),
+            ParseErrorKind::MixedEnumeration => {
+                write!(f, "set enumerations must not mix booleans and numbers")
+            },
+        }?;
+
+        write!(f, " at {}..{}", self.span.start, self.span.end)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+///////////////////////////////////////////////////////////////////////////
+// Lexer
+///////////////////////////////////////////////////////////////////////////
+#[derive(Clone, PartialEq, Debug)]
+enum Token {
+    LBracket,
+    RBracket,
+    LParen,
+    RParen,
+    LBrace,
+    RBrace,
+    Comma,
+    DotDot,
+    Union,
+    Intersection,
+    Difference,
+    Times,
+    Caret,
+    Superscript(usize),
+    Number { text: String, is_float: bool },
+    Infinity(Option<bool>),
+    Bool(bool),
+    Set(StandardSet),
+    Empty,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+enum StandardSet {
+    Reals,
+    Integers,
+    Naturals,
+    Booleans,
+}
+
+fn superscript_digit(c: char) -> Option<usize> {
+    "⁰¹²³⁴⁵⁶⁷⁸⁹".chars().position(|d| d == c)
+}
+
+struct Lexer<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn peek(&self) -> Option<char> { self.input[self.pos..].chars().next() }
+
+    fn peek_nth(&self, n: usize) -> Option<char> { self.input[self.pos..].chars().nth(n) }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek()?;
+
+        self.pos += c.len_utf8();
+
+        Some(c)
+    }
+
+    fn eat_while<F: Fn(char) -> bool>(&mut self, pred: F) {
+        while self.peek().map_or(false, &pred) { self.bump(); }
+    }
+
+    fn number(&mut self, start: usize) -> Token {
+        let mut is_float = false;
+
+        self.eat_while(|c| c.is_ascii_digit());
+
+        if self.peek() == Some('.') && self.peek_nth(1).map_or(false, |c| c.is_ascii_digit()) {
+            is_float = true;
+
+            self.bump();
+            self.eat_while(|c| c.is_ascii_digit());
+        }
+
+        if matches!(self.peek(), Some('e') | Some('E')) {
+            let sign = matches!(self.peek_nth(1), Some('+') | Some('-')) as usize;
+
+            if self.peek_nth(1 + sign).map_or(false, |c| c.is_ascii_digit()) {
+                is_float = true;
+
+                for _ in 0..(1 + sign) { self.bump(); }
+
+                self.eat_while(|c| c.is_ascii_digit());
+            }
+        }
+
+        Token::Number { text: self.input[start..self.pos].to_owned(), is_float }
+    }
+
+    fn word(&mut self, start: usize) -> Result<Token, ParseError> {
+        self.eat_while(|c| c.is_ascii_alphabetic());
+
+        // Single-letter symbols may be written without separating whitespace,
+        // e.g. `RxZ`, so only take the first letter of any other word.
+        if !matches!(&self.input[start..self.pos], "inf" | "true" | "false") {
+            self.pos = start + 1;
+        }
+
+        Ok(match &self.input[start..self.pos] {
+            "U" => Token::Union,
+            "x" => Token::Times,
+            "R" => Token::Set(StandardSet::Reals),
+            "Z" => Token::Set(StandardSet::Integers),
+            "N" => Token::Set(StandardSet::Naturals),
+            "B" => Token::Set(StandardSet::Booleans),
+            "inf" => Token::Infinity(None),
+            "true" => Token::Bool(true),
+            "false" => Token::Bool(false),
+            word => return Err(ParseError {
+                kind: ParseErrorKind::UnexpectedToken {
+                    found: word.to_owned(),
+                    expected: "a space",
+                },
+                span: start..self.pos,
+            }),
+        })
+    }
+
+    fn next_token(&mut self) -> Option<Result<(Token, Range<usize>), ParseError>> {
+        self.eat_while(char::is_whitespace);
+
+        let start = self.pos;
+        let c = self.bump()?;
+
+        let token = match c {
+            '[' => Token::LBracket,
+            ']' => Token::RBracket,
+            '(' => Token::LParen,
+            ')' => Token::RParen,
+            '{' => Token::LBrace,
+            '}' => Token::RBrace,
+            ',' => Token::Comma,
+            '.' if self.peek() == Some('.') => { self.bump(); Token::DotDot },
+            '∪' => Token::Union,
+            '∩' | '&' => Token::Intersection,
+            '∖' | '\\' => Token::Difference,
+            '×' | '*' => Token::Times,
+            '^' => Token::Caret,
+            '∞' => Token::Infinity(None),
+            'ℝ' => Token::Set(StandardSet::Reals),
+            'ℤ' => Token::Set(StandardSet::Integers),
+            'ℕ' => Token::Set(StandardSet::Naturals),
+            '𝔹' => Token::Set(StandardSet::Booleans),
+            '∅' => Token::Empty,
+            '0'..='9' => self.number(start),
+            '+' | '-' => match self.peek() {
+                Some('0'..='9') => self.number(start),
+                Some('∞') => { self.bump(); Token::Infinity(Some(c == '+')) },
+                Some('i') if self.input[self.pos..].starts_with("inf") => {
+                    self.pos += 3;
+
+                    Token::Infinity(Some(c == '+'))
+                },
+                _ => return Some(Err(ParseError {
+                    kind: ParseErrorKind::UnexpectedChar(c),
+                    span: start..self.pos,
+                })),
+            },
+            c if superscript_digit(c).is_some() => {
+                self.eat_while(|c| superscript_digit(c).is_some());
+
+                let n = self.input[start..self.pos]
+                    .chars()
+                    .try_fold(0usize, |n, c| n.checked_mul(10)?.checked_add(superscript_digit(c)?));
+
+                match n {
+                    Some(n) => Token::Superscript(n),
+                    None => return Some(Err(ParseError {
+                        kind: ParseErrorKind::InvalidNumber,
+                        span: start..self.pos,
+                    })),
+                }
+            },
+            c if c.is_ascii_alphabetic() => return Some(self.word(start).map(|t| (t, start..self.pos))),
+            c => return Some(Err(ParseError {
+                kind: ParseErrorKind::UnexpectedChar(c),
+                span: start..self.pos,
+            })),
+        };
+
+        Some(Ok((token, start..self.pos)))
+    }
+}
+
+///////////////////////////////////////////////////////////////////////////
+// Parser
+///////////////////////////////////////////////////////////////////////////
+struct Parser<'a> {
+    input: &'a str,
+    tokens: Vec<(Token, Range<usize>)>,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn peek(&self) -> Option<&Token> { self.tokens.get(self.pos).map(|(t, _)| t) }
+
+    fn current(&self) -> Option<(Token, Range<usize>)> { self.tokens.get(self.pos).cloned() }
+
+    fn eat(&mut self, token: &Token) -> bool {
+        if self.peek() == Some(token) { self.pos += 1; true } else { false }
+    }
+
+    fn error(&self, expected: &'static str) -> ParseError {
+        match self.tokens.get(self.pos) {
+            Some((_, span)) => ParseError {
+                kind: ParseErrorKind::UnexpectedToken {
+                    found: self.input[span.clone()].to_owned(),
+                    expected,
+                },
+                span: span.clone(),
+            },
+            None => ParseError {
+                kind: ParseErrorKind::UnexpectedEnd { expected },
+                span: self.input.len()..self.input.len(),
+            },
+        }
+    }
+
+    fn expect(&mut self, token: &Token, expected: &'static str) -> Result<Range<usize>, ParseError> {
+        match self.tokens.get(self.pos) {
+            Some((t, span)) if t == token => {
+                self.pos += 1;
+
+                Ok(span.clone())
+            },
+            _ => Err(self.error(expected)),
+        }
+    }
+
+    fn union(&mut self) -> Result<SpaceDescriptor, ParseError> {
+        let mut space = self.difference()?;
+
+        while self.eat(&Token::Union) {
+            space = SpaceDescriptor::Union(Box::new(space), Box::new(self.difference()?));
+        }
+
+        Ok(space)
+    }
+
+    fn difference(&mut self) -> Result<SpaceDescriptor, ParseError> {
+        let mut space = self.intersection()?;
+
+        while self.eat(&Token::Difference) {
+            let rhs = SpaceDescriptor::Complement(Box::new(self.intersection()?));
+
+            space = SpaceDescriptor::Intersection(Box::new(space), Box::new(rhs));
+        }
+
+        Ok(space)
+    }
+
+    fn intersection(&mut self) -> Result<SpaceDescriptor, ParseError> {
+        let mut space = self.product()?;
+
+        while self.eat(&Token::Intersection) {
+            space = SpaceDescriptor::Intersection(Box::new(space), Box::new(self.product()?));
+        }
+
+        Ok(space)
+    }
+
+    fn product(&mut self) -> Result<SpaceDescriptor, ParseError> {
+        let mut factors = vec![self.power()?];
+
+        while self.eat(&Token::Times) { factors.push(self.power()?); }
+
+        Ok(if factors.len() == 1 { factors.pop().unwrap() } else { SpaceDescriptor::Tuple(factors) })
+    }
+
+    fn power(&mut self) -> Result<SpaceDescriptor, ParseError> {
+        let mut space = self.atom()?;
+
+        loop {
+            let len = match self.peek() {
+                Some(Token::Superscript(n)) => { let n = *n; self.pos += 1; n },
+                Some(Token::Caret) => {
+                    self.pos += 1;
+
+                    match self.current() {
+                        Some((Token::Number { text, is_float: false }, span)) => {
+                            self.pos += 1;
+
+                            text.parse().map_err(|_| ParseError {
+                                kind: ParseErrorKind::InvalidNumber,
+                                span,
+                            })?
+                        },
+                        _ => return Err(self.error("a non-negative integer")),
+                    }
+                },
+                _ => return Ok(space),
+            };
+
+            space = SpaceDescriptor::Homogeneous { space: Box::new(space), len };
+        }
+    }
+
+    fn atom(&mut self) -> Result<SpaceDescriptor, ParseError> {
+        let token = match self.peek() {
+            Some(t) => t.clone(),
+            None => return Err(self.error("a space")),
+        };
+
+        match token {
+            Token::LBracket => { self.pos += 1; self.interval(false) },
+            Token::LParen => {
+                self.pos += 1;
+
+                match self.peek() {
+                    Some(Token::Number { .. }) | Some(Token::Infinity(_)) => self.interval(true),
+                    _ => {
+                        let space = self.union()?;
+
+                        self.expect(&Token::RParen, "')'")?;
+
+                        Ok(space)
+                    },
+                }
+            },
+            Token::LBrace => { self.pos += 1; self.set() },
+            Token::Set(set) => {
+                self.pos += 1;
+
+                let unbounded = |kind| SpaceDescriptor::Interval { kind, left: None, right: None };
+
+                Ok(match set {
+                    StandardSet::Reals => unbounded(ScalarKind::Float),
+                    StandardSet::Integers => unbounded(ScalarKind::Int),
+                    StandardSet::Naturals => SpaceDescriptor::Interval {
+                        kind: ScalarKind::Int,
+                        left: Some(OpenOrClosed::Closed(DynValue::Int(0))),
+                        right: None,
+                    },
+                    StandardSet::Booleans => closed(DynValue::Bool(false), DynValue::Bool(true)),
+                })
+            },
+            Token::Empty => { self.pos += 1; Ok(SpaceDescriptor::Empty) },
+            _ => Err(self.error("a space")),
+        }
+    }
+
+    // Returns `None` for an infinite bound.
+    fn bound(&mut self, left: bool) -> Result<(Option<f64>, Range<usize>), ParseError> {
+        let (token, span) = match self.current() {
+            Some(t) => t,
+            None => return Err(self.error("a number or infinity")),
+        };
+
+        let bound = match token {
+            Token::Number { text, .. } => text.parse().map(Some).ok(),
+            Token::Infinity(Some(positive)) if positive == left => return Err(ParseError {
+                kind: ParseErrorKind::MisplacedInfinity,
+                span,
+            }),
+            Token::Infinity(_) => Some(None),
+            _ => return Err(self.error("a number or infinity")),
+        };
+
+        self.pos += 1;
+
+        bound.map(|b| (b, span.clone())).ok_or(ParseError { kind: ParseErrorKind::InvalidNumber, span })
+    }
+
+    fn interval(&mut self, left_open: bool) -> Result<SpaceDescriptor, ParseError> {
+        let (lb, lspan) = self.bound(true)?;
+
+        self.expect(&Token::Comma, "','")?;
+
+        let (rb, rspan) = self.bound(false)?;
+        let right_open = match self.peek() {
+            Some(Token::RParen) => true,
+            Some(Token::RBracket) => false,
+            _ => return Err(self.error("')' or ']'")),
+        };
+
+        self.pos += 1;
+
+        if let (Some(l), Some(r)) = (lb, rb) {
+            if l > r {
+                return Err(ParseError { kind: ParseErrorKind::ReversedBounds, span: lspan.start..rspan.end });
+            }
+        }
+
+        // Brackets always denote real intervals; integers are written as ranges.
+        let to_bound = |b: Option<f64>, open: bool, span: Range<usize>| {
+            let val = match b {
+                Some(x) => DynValue::Float(x),
+                None if open => return Ok(None),
+                None => return Err(ParseError {
+                    kind: ParseErrorKind::ClosedInfiniteBound,
+                    span,
+                }),
+            };
+
+            Ok(Some(if open { OpenOrClosed::Open(val) } else { OpenOrClosed::Closed(val) }))
+        };
+
+        Ok(SpaceDescriptor::Interval {
+            kind: ScalarKind::Float,
+            left: to_bound(lb, left_open, lspan)?,
+            right: to_bound(rb, right_open, rspan)?,
+        })
+    }
+
+    fn range_end(&mut self, left: bool) -> Result<(Option<i128>, Range<usize>), ParseError> {
+        match self.current() {
+            Some((Token::Number { text, is_float: false }, span)) => {
+                self.pos += 1;
+
+                text.parse()
+                    .map(|x| (Some(x), span.clone()))
+                    .map_err(|_| ParseError { kind: ParseErrorKind::InvalidNumber, span })
+            },
+            Some((Token::Infinity(Some(positive)), span)) if positive == left => Err(ParseError {
+                kind: ParseErrorKind::MisplacedInfinity,
+                span,
+            }),
+            Some((Token::Infinity(_), span)) => { self.pos += 1; Ok((None, span)) },
+            _ => Err(self.error("an integer or infinity")),
+        }
+    }
+
+    fn range(&mut self) -> Result<SpaceDescriptor, ParseError> {
+        let (lo, lspan) = self.range_end(true)?;
+
+        self.expect(&Token::DotDot, "'..'")?;
+
+        let (hi, hspan) = self.range_end(false)?;
+
+        self.expect(&Token::RBrace, "'}'")?;
+
+        if let (Some(lo), Some(hi)) = (lo, hi) {
+            if lo > hi {
+                return Err(ParseError { kind: ParseErrorKind::ReversedBounds, span: lspan.start..hspan.end });
+            }
+        }
+
+        Ok(SpaceDescriptor::Interval {
+            kind: ScalarKind::Int,
+            left: lo.map(|x| OpenOrClosed::Closed(DynValue::Int(x))),
+            right: hi.map(|x| OpenOrClosed::Closed(DynValue::Int(x))),
+        })
+    }
+
+    fn set(&mut self) -> Result<SpaceDescriptor, ParseError> {
+        if self.eat(&Token::RBrace) { return Ok(SpaceDescriptor::Empty); }
+
+        // Integer ranges: {a..b}, where either end may be infinite.
+        if matches!(self.tokens.get(self.pos + 1), Some((Token::DotDot, _))) { return self.range(); }
+
+        let mut elements = vec![];
+
+        loop {
+            let (token, span) = match self.current() {
+                Some(t) => t,
+                None => return Err(self.error("a number or boolean")),
+            };
+
+            let val = match token {
+                Token::Bool(b) => DynValue::Bool(b),
+                Token::Number { text, is_float } => {
+                    let invalid = || ParseError { kind: ParseErrorKind::InvalidNumber, span: span.clone() };
+
+                    if is_float {
+                        DynValue::Float(text.parse().map_err(|_| invalid())?)
+                    } else {
+                        DynValue::Int(text.parse().map_err(|_| invalid())?)
+                    }
+                },
+                _ => return Err(self.error("a number or boolean")),
+            };
+
+            self.pos += 1;
+
+            let is_bool = |v: &DynValue| v.kind() == Some(ScalarKind::Bool);
+
+            if elements.first().map_or(false, |first| is_bool(first) != is_bool(&val)) {
+                return Err(ParseError { kind: ParseErrorKind::MixedEnumeration, span });
+            }
+
+            elements.push(val);
+
+            if self.eat(&Token::RBrace) { break; }
+
+            self.expect(&Token::Comma, "',' or '}'")?;
+        }
+
+        Ok(enumeration(elements))
+    }
+}
+
+fn closed(lo: DynValue, hi: DynValue) -> SpaceDescriptor {
+    SpaceDescriptor::Interval {
+        kind: lo.kind().unwrap(),
+        left: Some(OpenOrClosed::Closed(lo)),
+        right: Some(OpenOrClosed::Closed(hi)),
+    }
+}
+
+/// Returns the union of the singletons `{x}` for each `x` in `elements`.
+fn enumeration(elements: Vec<DynValue>) -> SpaceDescriptor {
+    let has_bools = elements.iter().any(|v| matches!(v, DynValue::Bool(_)));
+
+    if has_bools {
+        let f = elements.contains(&DynValue::Bool(false));
+        let t = elements.contains(&DynValue::Bool(true));
+
+        return closed(DynValue::Bool(!f), DynValue::Bool(t));
+    }
+
+    let is_float = elements.iter().any(|v| matches!(v, DynValue::Float(_)));
+
+    elements
+        .into_iter()
+        .map(|v| match v {
+            DynValue::Int(x) if is_float => DynValue::Float(x as f64),
+            v => v,
+        })
+        .map(|v| closed(v.clone(), v))
+        .reduce(|x, y| SpaceDescriptor::Union(Box::new(x), Box::new(y)))
+        .unwrap_or(SpaceDescriptor::Empty)
+}
+
+/// Parse a space from mathematical notation.
+///
+/// See the [module-level documentation](self) for the supported grammar.
+pub fn parse(input: &str) -> Result<SpaceDescriptor, ParseError> {
+    let mut lexer = Lexer { input, pos: 0 };
+    let mut tokens = vec![];
+
+    while let Some(token) = lexer.next_token() { tokens.push(token?); }
+
+    let mut parser = Parser { input, tokens, pos: 0 };
+    let space = parser.union()?;
+
+    if parser.pos < parser.tokens.len() { return Err(parser.error("end of input")); }
+
+    Ok(space)
+}
+
+impl FromStr for SpaceDescriptor {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<SpaceDescriptor, ParseError> { parse(s) }
+}
+
+///////////////////////////////////////////////////////////////////////////
+// Rendering
+///////////////////////////////////////////////////////////////////////////
+/// Output style used when rendering a space.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Style {
+    /// Plain text using unicode symbols, e.g. `[0, 1)² ∪ ∅`.
+    Unicode,
+
+    /// LaTeX math mode, e.g. `[0, 1)^{2} \cup \emptyset`.
+    Latex,
+}
+
+/// Binding strength of the outermost operator of a rendered space.
+///
+/// The levels mirror those of the parser, such that operands are
+/// parenthesised exactly when required.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+pub enum Precedence {
+    Union,
+    Difference,
+    Intersection,
+    Product,
+    Power,
+    Atom,
+}
+
+/// Trait for spaces that can be rendered in mathematical notation.
+///
+/// Types local to this crate implement `Display` through this trait; for
+/// foreign types, such as arrays and tuples, use `display()` instead.
+///
+/// ```
+/// # extern crate spaces;
+/// # use spaces::{Notation, intervals::Interval, ops::UnionPair};
+/// let space = ([Interval::closed_unchecked(0, 1); 3], Some(Interval::left_closed(0.5)));
+///
+/// assert_eq!(space.display().to_string(), "[0, 1]³ × [0.5, ∞)");
+/// assert_eq!(space.to_latex(), "[0, 1]^{3} \\times [0.5, \\infty)");
+/// ```
+pub trait Notation {
+    /// Write the space to `f` in the given style.
+    fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result;
+
+    /// Return the binding strength of the outermost operator.
+    fn precedence(&self) -> Precedence { Precedence::Atom }
+
+    /// Return a wrapper that implements `Display` using unicode notation.
+    fn display(&self) -> Displayed<'_, Self> where Self: Sized { Displayed(self, Style::Unicode) }
+
+    /// Render the space as LaTeX (math mode).
+    fn to_latex(&self) -> String { Displayed(self, Style::Latex).to_string() }
+}
+
+/// Wrapper implementing `Display` for any `Notation` type.
+pub struct Displayed<'a, S: ?Sized>(&'a S, Style);
+
+impl<'a, S: Notation + ?Sized> fmt::Display for Displayed<'a, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.0.fmt_notation(f, self.1) }
+}
+
+/// Write `space`, parenthesised if it binds more loosely than `min`.
+pub(crate) fn fmt_operand<S: Notation + ?Sized>(
+    f: &mut fmt::Formatter<'_>,
+    space: &S,
+    style: Style,
+    min: Precedence,
+) -> fmt::Result {
+    if space.precedence() < min {
+        write!(f, "(")?;
+        space.fmt_notation(f, style)?;
+        write!(f, ")")
+    } else {
+        space.fmt_notation(f, style)
+    }
+}
+
+/// Write `lhs op rhs` for a binary set operation at the given precedence.
+pub(crate) fn fmt_binary<A: Notation + ?Sized, B: Notation + ?Sized>(
+    f: &mut fmt::Formatter<'_>,
+    style: Style,
+    lhs: &A,
+    rhs: &B,
+    prec: Precedence,
+) -> fmt::Result {
+    let (op, associative) = match (prec, style) {
+        (Precedence::Union, Style::Unicode) => ("\u{222A}", true),
+        (Precedence::Union, Style::Latex) => ("\\cup", true),
+        (Precedence::Difference, Style::Unicode) => ("\u{2216}", false),
+        (Precedence::Difference, Style::Latex) => ("\\setminus", false),
+        (Precedence::Intersection, Style::Unicode) => ("\u{2229}", true),
+        (Precedence::Intersection, Style::Latex) => ("\\cap", true),
+        _ => unreachable!(),
+    };
+
+    // Non-associative operators require a tighter right-hand operand.
+    let rhs_min = if associative { prec } else { Precedence::Intersection };
+
+    fmt_operand(f, lhs, style, prec)?;
+    write!(f, " {} ", op)?;
+    fmt_operand(f, rhs, style, rhs_min)
+}
+
+/// Write the product of `factors`, or a power if all factors render the same.
+pub(crate) fn fmt_product<S: Notation>(
+    f: &mut fmt::Formatter<'_>,
+    style: Style,
+    factors: &[S],
+) -> fmt::Result {
+    let rendered: Vec<String> = factors.iter().map(|s| Displayed(s, style).to_string()).collect();
+
+    match factors {
+        [] => fmt_empty_product(f, style),
+        [s] => fmt_power(f, style, s, 1),
+        [s, ..] if rendered.windows(2).all(|w| w[0] == w[1]) => fmt_power(f, style, s, factors.len()),
+        _ => {
+            let factors: Vec<&dyn Notation> = factors.iter().map(|s| s as &dyn Notation).collect();
+
+            fmt_tuple(f, style, &factors)
+        },
+    }
+}
+
+/// Returns the precedence of the rendering produced by `fmt_product`.
+pub(crate) fn product_precedence<S: Notation>(factors: &[S]) -> Precedence {
+    let rendered: Vec<String> = factors.iter().map(|s| s.display().to_string()).collect();
+
+    if factors.is_empty() {
+        Precedence::Atom
+    } else if rendered.windows(2).all(|w| w[0] == w[1]) {
+        Precedence::Power
+    } else {
+        Precedence::Product
+    }
+}
+
+pub(crate) fn fmt_empty_product(f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+    match style {
+        Style::Unicode => write!(f, "{{()}}"),
+        Style::Latex => write!(f, "\\{{()\\}}"),
+    }
+}
+
+/// Write the `n`-fold power of `space`.
+pub(crate) fn fmt_power<S: Notation + ?Sized>(
+    f: &mut fmt::Formatter<'_>,
+    style: Style,
+    space: &S,
+    n: usize,
+) -> fmt::Result {
+    fmt_operand(f, space, style, Precedence::Atom)?;
+
+    match style {
+        Style::Unicode => n.to_string().chars().try_for_each(|d| {
+            let d = d.to_digit(10).unwrap() as usize;
+
+            write!(f, "{}", "⁰¹²³⁴⁵⁶⁷⁸⁹".chars().nth(d).unwrap())
+        }),
+        Style::Latex => write!(f, "^{{{}}}", n),
+    }
+}
+
+/// Write the cartesian product of `factors`.
+pub(crate) fn fmt_tuple(
+    f: &mut fmt::Formatter<'_>,
+    style: Style,
+    factors: &[&dyn Notation],
+) -> fmt::Result {
+    for (i, s) in factors.iter().enumerate() {
+        if i > 0 {
+            match style {
+                Style::Unicode => write!(f, " \u{00D7} ")?,
+                Style::Latex => write!(f, " \\times ")?,
+            }
+        }
+
+        fmt_operand(f, *s, style, Precedence::Power)?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn fmt_empty(f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+    match style {
+        Style::Unicode => write!(f, "\u{2205}"),
+        Style::Latex => write!(f, "\\emptyset"),
+    }
+}
+
+/// Write the index set `{0..n-1}`, or the empty set if `n` is zero.
+pub(crate) fn fmt_range(f: &mut fmt::Formatter<'_>, style: Style, n: usize) -> fmt::Result {
+    match n {
+        0 => fmt_empty(f, style),
+        _ => fmt_int_range(f, style, Some(0), Some(n as i128 - 1)),
+    }
+}
+
+/// Write the integers from `lo` to `hi` inclusive, where `None` denotes an
+/// infinite end.
+pub(crate) fn fmt_int_range(
+    f: &mut fmt::Formatter<'_>,
+    style: Style,
+    lo: Option<i128>,
+    hi: Option<i128>,
+) -> fmt::Result {
+    match (lo, hi, style) {
+        (Some(lo), Some(hi), _) if lo > hi => fmt_empty(f, style),
+        (Some(0), None, Style::Unicode) => write!(f, "\u{2115}"),
+        (Some(0), None, Style::Latex) => write!(f, "\\mathbb{{N}}"),
+        (None, None, Style::Unicode) => write!(f, "\u{2124}"),
+        (None, None, Style::Latex) => write!(f, "\\mathbb{{Z}}"),
+        (Some(lo), Some(hi), Style::Unicode) => write!(f, "{{{}..{}}}", lo, hi),
+        (Some(lo), None, Style::Unicode) => write!(f, "{{{}..\u{221E}}}", lo),
+        (None, Some(hi), Style::Unicode) => write!(f, "{{-\u{221E}..{}}}", hi),
+        (Some(lo), Some(hi), Style::Latex) => write!(f, "\\{{{}, \\dots, {}\\}}", lo, hi),
+        (Some(lo), None, Style::Latex) => write!(f, "\\{{{}, \\dots\\}}", lo),
+        (None, Some(hi), Style::Latex) => write!(f, "\\{{\\dots, {}\\}}", hi),
+    }
+}
+
+/// Write the left bound of an interval.
+pub(crate) fn fmt_left<V: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    style: Style,
+    bound: Option<&OpenOrClosed<V>>,
+) -> fmt::Result {
+    match (bound, style) {
+        (Some(OpenOrClosed::Open(v)), _) => write!(f, "({}", v),
+        (Some(OpenOrClosed::Closed(v)), _) => write!(f, "[{}", v),
+        (None, Style::Unicode) => write!(f, "(-\u{221E}"),
+        (None, Style::Latex) => write!(f, "(-\\infty"),
+    }
+}
+
+/// Write the right bound of an interval.
+pub(crate) fn fmt_right<V: fmt::Display>(
+    f: &mut fmt::Formatter<'_>,
+    style: Style,
+    bound: Option<&OpenOrClosed<V>>,
+) -> fmt::Result {
+    match (bound, style) {
+        (Some(OpenOrClosed::Open(v)), _) => write!(f, "{})", v),
+        (Some(OpenOrClosed::Closed(v)), _) => write!(f, "{}]", v),
+        (None, Style::Unicode) => write!(f, "\u{221E})"),
+        (None, Style::Latex) => write!(f, "\\infty)"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::DynSpace;
+
+    fn interval(kind: ScalarKind, left: Option<OpenOrClosed<DynValue>>, right: Option<OpenOrClosed<DynValue>>) -> SpaceDescriptor {
+        SpaceDescriptor::Interval { kind, left, right }
+    }
+
+    #[test]
+    fn test_intervals() {
+        use self::OpenOrClosed::*;
+
+        assert_eq!(parse("[0, 1)").unwrap(), interval(
+            ScalarKind::Float, Some(Closed(DynValue::Float(0.0))), Some(Open(DynValue::Float(1.0)))
+        ));
+        assert_eq!(parse("{-2..3}").unwrap(), interval(
+            ScalarKind::Int, Some(Closed(DynValue::Int(-2))), Some(Closed(DynValue::Int(3)))
+        ));
+        assert_eq!(parse("{-inf..3}").unwrap(), interval(
+            ScalarKind::Int, None, Some(Closed(DynValue::Int(3)))
+        ));
+        assert_eq!(parse("{0..∞}").unwrap(), parse("ℕ").unwrap());
+        assert_eq!(parse("{-∞..∞}").unwrap(), parse("Z").unwrap());
+        assert_eq!(parse("(-inf, 2.5]").unwrap(), interval(
+            ScalarKind::Float, None, Some(Closed(DynValue::Float(2.5)))
+        ));
+        assert_eq!(parse("(0,1e3)").unwrap(), interval(
+            ScalarKind::Float, Some(Open(DynValue::Float(0.0))), Some(Open(DynValue::Float(1000.0)))
+        ));
+        assert_eq!(parse("(-∞, ∞)").unwrap(), parse("R").unwrap());
+        assert_eq!(parse("ℝ").unwrap(), interval(ScalarKind::Float, None, None));
+        assert_eq!(parse("ℕ").unwrap(), interval(ScalarKind::Int, Some(Closed(DynValue::Int(0))), None));
+    }
+
+    #[test]
+    fn test_union() {
+        let s = parse("[0, 1) ∪ (2, +inf)").unwrap();
+
+        assert_eq!(s, parse("[0,1) U (2,inf)").unwrap());
+        assert!(s.contains(&DynValue::Float(0.5)));
+        assert!(!s.contains(&DynValue::Float(1.5)));
+        assert!(s.contains(&DynValue::Float(2.5)));
+    }
+
+    #[test]
+    fn test_sets() {
+        let s = parse("{0..9}^3").unwrap();
+
+        assert_eq!(s, parse("{0..9}³").unwrap());
+        assert_eq!(s.cardinality(), Some(1000));
+
+        assert_eq!(parse("{true, false}").unwrap(), parse("B").unwrap());
+        assert_eq!(parse("{true}").unwrap().cardinality(), Some(1));
+        assert_eq!(parse("{1, 3, 5}").unwrap().cardinality(), Some(3));
+        assert_eq!(parse("{}").unwrap(), SpaceDescriptor::Empty);
+        assert_eq!(parse("∅").unwrap(), SpaceDescriptor::Empty);
+    }
+
+    #[test]
+    fn test_products() {
+        let s = parse("(0,1] × {true,false}").unwrap();
+
+        assert_eq!(s, parse("(0, 1] x B").unwrap());
+        assert!(s.contains(&DynValue::from((0.5, false))));
+        assert!(!s.contains(&DynValue::from((0.0, false))));
+
+        let s = parse("ℝ^4").unwrap();
+
+        assert_eq!(s, parse("R⁴").unwrap());
+        assert!(s.contains(&DynValue::from([0.0; 4])));
+
+        assert_eq!(parse("RxZxB").unwrap(), parse("ℝ × ℤ × 𝔹").unwrap());
+        assert_eq!(parse("(R x Z)^2").unwrap(), SpaceDescriptor::Homogeneous {
+            space: Box::new(parse("R x Z").unwrap()),
+            len: 2,
+        });
+    }
+
+    #[test]
+    fn test_precedence() {
+        let s = parse("{0..10} \\ {2..3} ∩ {0..5}").unwrap();
+
+        assert!(s.contains(&DynValue::Int(1)));
+        assert!(!s.contains(&DynValue::Int(2)));
+        assert!(s.contains(&DynValue::Int(7)));
+
+        let s = parse("{0..1} U {5..6} x {0..1}").unwrap();
+
+        assert!(s.contains(&DynValue::Int(0)));
+        assert!(s.contains(&DynValue::from((5, 0))));
+    }
+
+    #[test]
+    fn test_errors() {
+        let err = |s: &str| parse(s).unwrap_err();
+
+        assert_eq!(err("[0, 1) U (2, +inf"), ParseError {
+            kind: ParseErrorKind::UnexpectedEnd { expected: "')' or ']'" },
+            span: 17..17,
+        });
+        assert_eq!(err("[0, inf]"), ParseError {
+            kind: ParseErrorKind::ClosedInfiniteBound,
+            span: 4..7,
+        });
+        assert_eq!(err("(+inf, 0]").kind, ParseErrorKind::MisplacedInfinity);
+        assert_eq!(err("[0; 1]"), ParseError {
+            kind: ParseErrorKind::UnexpectedChar(';'),
+            span: 2..3,
+        });
+        assert_eq!(err("ℝ^x"), ParseError {
+            kind: ParseErrorKind::UnexpectedToken { found: "x".to_owned(), expected: "a non-negative integer" },
+            span: 4..5,
+        });
+        assert_eq!(err("{true, 1}"), ParseError {
+            kind: ParseErrorKind::MixedEnumeration,
+            span: 7..8,
+        });
+        assert_eq!(err("[0, 1] ]").kind, ParseErrorKind::UnexpectedToken {
+            found: "]".to_owned(),
+            expected: "end of input",
+        });
+        assert_eq!(err("{0..1.5}").span, 4..7);
+        assert_eq!(err("[1, 0.5]"), ParseError {
+            kind: ParseErrorKind::ReversedBounds,
+            span: 1..7,
+        });
+        assert_eq!(err("{9..0}"), ParseError {
+            kind: ParseErrorKind::ReversedBounds,
+            span: 1..5,
+        });
+        assert_eq!(err("{+inf..0}").kind, ParseErrorKind::MisplacedInfinity);
+        assert_eq!(err("foo").span, 0..1);
+        assert_eq!(
+            err("[0, 1) U").to_string(),
+            "expected a space, found end of input at 8..8"
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        use crate::{
+            intervals::{partitions, Interval},
+            ops::{ComplementOf, DifferencePair, IntersectionPair, UnionPair},
+            real::reals,
+            Homogeneous,
+        };
+
+        let unit = Interval::closed_unchecked(0.0, 1.0);
+
+        assert_eq!(reals::<f64>().display().to_string(), "(-∞, ∞)");
+        assert_eq!([unit; 2].display().to_string(), "[0, 1]²");
+        assert_eq!([unit, Interval::closed_unchecked(0.0, 2.0)].display().to_string(), "[0, 1] × [0, 2]");
+        assert_eq!(Homogeneous::new(unit, 12).to_string(), "[0, 1]¹²");
+        assert_eq!(None::<crate::discrete::Binary>.display().to_string(), "∅");
+        assert_eq!(partitions::Uniform { size: 10, left: 0.0, right: 1.0 }.display().to_string(), "{0..9}");
+
+        assert_eq!(IntersectionPair(unit, Interval::open_unchecked(0.5, 2.0)).to_string(), "[0, 1] ∩ (0.5, 2)");
+        assert_eq!(DifferencePair(unit, Interval::open_unchecked(0.5, 2.0)).to_string(), "[0, 1] ∖ (0.5, 2)");
+        assert_eq!(ComplementOf([unit; 2]).to_string(), "([0, 1]²)ᶜ");
+
+        // Operands are parenthesised only where necessary.
+        let u = UnionPair(unit, Interval::degenerate(2.0));
+
+        assert_eq!([u; 3].display().to_string(), "([0, 1] ∪ [2, 2])³");
+        assert_eq!((u, unit).display().to_string(), "([0, 1] ∪ [2, 2]) × [0, 1]");
+        assert_eq!(UnionPair(u, unit).to_string(), "[0, 1] ∪ [2, 2] ∪ [0, 1]");
+        assert_eq!(IntersectionPair(u, unit).to_string(), "([0, 1] ∪ [2, 2]) ∩ [0, 1]");
+    }
+
+    #[test]
+    fn test_latex() {
+        use crate::{intervals::Interval, ops::UnionPair};
+
+        let unit = Interval::closed_unchecked(0, 1);
+
+        assert_eq!(UnionPair(unit, Interval::left_open(3)).to_latex(), "[0, 1] \\cup (3, \\infty)");
+        assert_eq!(([unit; 10], None::<crate::discrete::Binary>).to_latex(), "[0, 1]^{10} \\times \\emptyset");
+        assert_eq!(parse("{0..4} x {true, false}").unwrap().to_latex(), "\\{0, \\dots, 4\\} \\times \\{\\mathrm{false}, \\mathrm{true}\\}");
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for s in [
+            "[0, 1) ∪ (2, ∞)",
+            "{0..9}³",
+            "(-∞, ∞)⁴",
+            "(-∞, 0] ∪ (-∞, 0.5)",
+            "(0.5, 1.0] × {false, true}",
+            "([0, 1] ∪ [5, 6])² ∖ {1..3}",
+            "[0, 10] ∖ ([2, 3] ∖ [2, 2])",
+            "(ℝ × ℤ)²",
+            "{-∞..0} ∪ {3..∞} ∪ ℕ",
+        ] {
+            let d = parse(s).unwrap();
+
+            assert_eq!(parse(&d.to_string()).unwrap(), d, "{}", s);
+        }
+
+        assert_eq!(parse("{0..9}^3").unwrap().to_string(), "{0..9}³");
+    }
+}
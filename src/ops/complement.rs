@@ -0,0 +1,123 @@
+use crate::{Space, notation::{fmt_operand, Notation, Precedence, Style}};
+use std::fmt;
+
+/// Trait for types that support the complement operation.
+///
+/// The complement of a set is the set containing all such elements of the
+/// universe (i.e. the space of `Self::Value`) that are not present in the set.
+pub trait Complement: Space {
+    type Output: Space<Value = Self::Value>;
+
+    fn complement(self) -> Self::Output;
+}
+
+/// Type representing the complement of an arbitrary space.
+///
+/// This is used where the complement of a space cannot be expressed exactly
+/// by another concrete space type, such as for `[D; N]`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct ComplementOf<S: Space>(pub S);
+
+impl<S> Complement for ComplementOf<S>
+where
+    S: Space,
+    ComplementOf<S>: Space<Value = S::Value>,
+{
+    type Output = S;
+
+    fn complement(self) -> S { self.0 }
+}
+
+impl<A, B> Complement for super::UnionPair<A, B>
+where
+    A: Complement,
+    B: Complement<Value = A::Value>,
+
+    A::Output: super::DecidableEmptiness<B::Output>,
+{
+    type Output = super::IntersectionPair<A::Output, B::Output>;
+
+    fn complement(self) -> Self::Output {
+        super::IntersectionPair(self.0.complement(), self.1.complement())
+    }
+}
+
+impl<A, B> Complement for super::IntersectionPair<A, B>
+where
+    A: Complement + super::DecidableEmptiness<B>,
+    B: Complement<Value = A::Value>,
+{
+    type Output = super::UnionPair<A::Output, B::Output>;
+
+    fn complement(self) -> Self::Output {
+        super::UnionPair(self.0.complement(), self.1.complement())
+    }
+}
+
+impl<S: Space + Notation> Notation for ComplementOf<S> {
+    fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+        fmt_operand(f, &self.0, style, Precedence::Atom)?;
+
+        match style {
+            Style::Unicode => write!(f, "\u{1D9C}"),
+            Style::Latex => write!(f, "^{{c}}"),
+        }
+    }
+
+    fn precedence(&self) -> Precedence { Precedence::Power }
+}
+
+impl<S: Space + Notation> fmt::Display for ComplementOf<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.fmt_notation(f, Style::Unicode) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intervals::Interval;
+    use crate::ops::UnionPair;
+
+    #[test]
+    fn test_de_morgan() {
+        let u = UnionPair(Interval::right_closed(-1.0f64), Interval::left_closed(1.0f64));
+        let c = u.complement();
+
+        assert!(!c.is_empty());
+        assert!(!c.contains(&-1.0));
+        assert!(c.contains(&0.0));
+        assert!(!c.contains(&1.0));
+
+        let cc = c.complement();
+
+        assert!(cc.contains(&-1.0));
+        assert!(!cc.contains(&0.0));
+        assert!(cc.contains(&1.0));
+    }
+
+    #[test]
+    fn test_complement_of_array() {
+        let c = [Interval::closed_unchecked(0.0f64, 1.0); 2].complement();
+
+        assert_eq!(c, ComplementOf([Interval::closed_unchecked(0.0f64, 1.0); 2]));
+        assert!(!c.is_empty());
+        assert!(c.contains(&[0.5, 2.0]));
+        assert!(!c.contains(&[0.5, 0.5]));
+
+        assert_eq!(c.complement(), [Interval::closed_unchecked(0.0f64, 1.0); 2]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let c = ComplementOf([Interval::closed_unchecked(0.0f64, 1.0); 2]);
+        let json = serde_json::to_string(&c).unwrap();
+
+        assert_eq!(json, r#"[{"left":0.0,"right":1.0},{"left":0.0,"right":1.0}]"#);
+        assert_eq!(serde_json::from_str::<ComplementOf<_>>(&json).unwrap(), c);
+    }
+}
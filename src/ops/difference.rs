@@ -0,0 +1,109 @@
+use crate::{Space, notation::{fmt_binary, Notation, Precedence, Style}};
+use super::{Complement, DecidableEmptiness};
+use std::fmt;
+
+/// Trait for types that support the set difference operation.
+///
+/// The difference between two sets, `A \ B`, is the set containing all such
+/// elements of `A` that are not present in `B`.
+pub trait Difference<Rhs: Space = Self>: Space {
+    type Output: Space;
+
+    fn difference(self, rhs: Rhs) -> Option<Self::Output>;
+}
+
+pub type DifferenceOf<S, T> = <S as Difference<T>>::Output;
+
+/// Type representing the difference between two arbitrary spaces.
+///
+/// __Note__: this type only implements `Space` if the emptiness of `A ∩ B'`
+/// can be decided, where `B'` is the complement of `B`.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct DifferencePair<A: Space, B: Space<Value = A::Value>>(pub A, pub B);
+
+impl<A, B> Space for DifferencePair<A, B>
+where
+    A: DecidableEmptiness<<B as Complement>::Output>,
+    B: Complement<Value = <A as Space>::Value> + Clone,
+{
+    type Value = A::Value;
+
+    fn is_empty(&self) -> bool { self.0.intersection_is_empty(&self.1.clone().complement()) }
+
+    fn contains(&self, val: &A::Value) -> bool { self.0.contains(val) && !self.1.contains(val) }
+}
+
+impl<A, B, C> Difference<C> for super::UnionPair<A, B>
+where
+    A: Difference<C>,
+    B: Difference<C, Value = A::Value>,
+    C: Space<Value = A::Value> + Clone,
+
+    DifferenceOf<B, C>: Space<Value = <DifferenceOf<A, C> as Space>::Value>,
+{
+    type Output = super::UnionPair<Option<DifferenceOf<A, C>>, Option<DifferenceOf<B, C>>>;
+
+    fn difference(self, rhs: C) -> Option<Self::Output> {
+        match (self.0.difference(rhs.clone()), self.1.difference(rhs)) {
+            (None, None) => None,
+            (x, y) => Some(super::UnionPair(x, y)),
+        }
+    }
+}
+
+impl<A, B> Notation for DifferencePair<A, B>
+where
+    A: Space + Notation,
+    B: Space<Value = A::Value> + Notation,
+{
+    fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+        fmt_binary(f, style, &self.0, &self.1, Precedence::Difference)
+    }
+
+    fn precedence(&self) -> Precedence { Precedence::Difference }
+}
+
+impl<A, B> fmt::Display for DifferencePair<A, B>
+where
+    A: Space + Notation,
+    B: Space<Value = A::Value> + Notation,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.fmt_notation(f, Style::Unicode) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intervals::Interval;
+    use crate::ops::UnionPair;
+
+    #[test]
+    fn test_union_difference() {
+        let u = UnionPair(Interval::closed_unchecked(0.0f64, 1.0), Interval::closed_unchecked(2.0, 3.0));
+        let d = u.difference(Interval::closed_unchecked(0.5, 2.5)).unwrap();
+
+        assert!(d.contains(&0.0));
+        assert!(!d.contains(&0.5));
+        assert!(!d.contains(&2.0));
+        assert!(d.contains(&3.0));
+
+        let u = UnionPair(Interval::closed_unchecked(0.0f64, 1.0), Interval::closed_unchecked(2.0, 3.0));
+
+        assert!(u.difference(Interval::closed_unchecked(-1.0, 4.0)).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let d = DifferencePair(Interval::closed_unchecked(0.0, 2.0), Interval::open_unchecked(1.0, 3.0));
+        let json = serde_json::to_string(&d).unwrap();
+
+        assert_eq!(json, r#"[{"left":0.0,"right":2.0},{"left":1.0,"right":3.0}]"#);
+        assert_eq!(serde_json::from_str::<DifferencePair<_, _>>(&json).unwrap(), d);
+    }
+}
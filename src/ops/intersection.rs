@@ -1,5 +1,14 @@
-use crate::{Space, OrderedSpace};
-use super::{OoC, LRB, min_val, max_val, Union, UnionPair};
+use crate::{
+    Space, OrderedSpace, FiniteSpace, IterableSpace, Validate,
+    intervals::{Interval, bounds},
+    notation::{fmt_binary, Notation, Precedence, Style},
+    validate::ContainmentError,
+};
+use super::{
+    OoC, LRB, limits_empty, min_val, max_val, ComplementOf, DifferencePair, Tighten, Union,
+    UnionPair,
+};
+use std::fmt;
 
 fn clip_ooc<T: PartialOrd>(x: OoC<T>, y: OoC<T>, cmp: impl Fn(T, T) -> LRB<T>) -> OoC<T> {
     use crate::intervals::bounds::OpenOrClosed::*;
@@ -12,6 +21,45 @@ fn clip_ooc<T: PartialOrd>(x: OoC<T>, y: OoC<T>, cmp: impl Fn(T, T) -> LRB<T>) -
     }
 }
 
+pub(super) fn pinch_inf<T: PartialOrd>(x: Option<OoC<T>>, y: Option<OoC<T>>) -> Option<OoC<T>> {
+    match (x, y) {
+        (Some(x), Some(y)) => Some(clip_ooc(x, y, max_val)),
+        (x, None) => x,
+        (None, y) => y,
+    }
+}
+
+pub(super) fn pinch_sup<T: PartialOrd>(x: Option<OoC<T>>, y: Option<OoC<T>>) -> Option<OoC<T>> {
+    match (x, y) {
+        (Some(x), Some(y)) => Some(clip_ooc(x, y, min_val)),
+        (x, None) => x,
+        (None, y) => y,
+    }
+}
+
+/// Returns true iff the intersection of two convex ordered spaces is empty.
+///
+/// Bounds are tightened before comparison, so the result is exact for
+/// discrete as well as continuous values.
+///
+/// __Note__: this is only exact for spaces without "holes", such as intervals;
+/// the hull of a `UnionPair`, for example, may contain values that the union
+/// itself does not.
+pub(crate) fn convex_disjoint<A, B>(a: &A, b: &B) -> bool
+where
+    A: OrderedSpace,
+    B: OrderedSpace<Value = A::Value>,
+
+    A::Value: Tighten,
+{
+    if a.is_empty() || b.is_empty() { return true; }
+
+    let inf = pinch_inf(a.inf().map(Tighten::tighten_inf), b.inf().map(Tighten::tighten_inf));
+    let sup = pinch_sup(a.sup().map(Tighten::tighten_sup), b.sup().map(Tighten::tighten_sup));
+
+    limits_empty(inf.as_ref(), sup.as_ref())
+}
+
 /// Trait for types that support the intersect operation.
 ///
 /// The intersection of a collection of sets is the set containing all
@@ -24,56 +72,114 @@ pub trait Intersection<Rhs: Space = Self>: Space {
 
 pub type IntersectionOf<S, T> = <S as Intersection<T>>::Output;
 
-// TODO - Add warning to docstring that explains why this type should be avoided.
-//        Namely, that you can lead to panic! when calling is_empty().
+/// Trait for pairs of spaces whose intersection can be tested for emptiness.
+///
+/// `IntersectionPair<A, B>` only implements `Space` when `A:
+/// DecidableEmptiness<B>`, so a failure to decide emptiness is caught at
+/// compile time rather than at runtime.
+///
+/// ```
+/// # extern crate spaces;
+/// # use spaces::{ops::DecidableEmptiness, intervals::Interval};
+/// let a = Interval::closed_unchecked(0.0, 1.0);
+///
+/// assert!(!a.intersection_is_empty(&Interval::lorc_unchecked(0.5, 2.0)));
+/// assert!(a.intersection_is_empty(&Interval::lorc_unchecked(1.0, 2.0)));
+/// ```
+pub trait DecidableEmptiness<Rhs: Space = Self>: Space {
+    /// Returns true iff `self` and `rhs` have no elements in common.
+    fn intersection_is_empty(&self, rhs: &Rhs) -> bool;
+}
+
 /// Type representing the intersection of two spaces.
+///
+/// __Note__: this type only implements `Space` if the emptiness of the
+/// intersection can be decided; see `DecidableEmptiness`.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
 pub struct IntersectionPair<A: Space, B: Space<Value = A::Value>>(pub A, pub B);
 
 impl<A, B> Space for IntersectionPair<A, B>
 where
-    A: Space,
+    A: DecidableEmptiness<B>,
     B: Space<Value = A::Value>,
 {
     type Value = A::Value;
 
-    fn is_empty(&self) -> bool {
-        if self.0.is_empty() || self.1.is_empty() { return true; }
-
-        panic!(
-            "It's not currently possible to evaluate IntersectionPair::is_empty when neither \
-            interior space are empty."
-        )
-    }
+    fn is_empty(&self) -> bool { self.0.intersection_is_empty(&self.1) }
 
     fn contains(&self, val: &A::Value) -> bool { self.0.contains(val) && self.1.contains(val) }
 }
 
 impl<A, B> OrderedSpace for IntersectionPair<A, B>
 where
-    A: OrderedSpace,
+    A: OrderedSpace + DecidableEmptiness<B>,
     B: OrderedSpace<Value = A::Value>,
 
     A::Value: PartialOrd,
 {
-    fn inf(&self) -> Option<OoC<A::Value>> {
-        match (self.0.inf(), self.1.inf()) {
-            (Some(left), Some(right)) => Some(clip_ooc(left, right, min_val)),
-            _ => None,
-        }
+    fn inf(&self) -> Option<OoC<A::Value>> { pinch_inf(self.0.inf(), self.1.inf()) }
+
+    fn sup(&self) -> Option<OoC<A::Value>> { pinch_sup(self.0.sup(), self.1.sup()) }
+}
+
+impl<A, B> Validate for IntersectionPair<A, B>
+where
+    A: Validate + DecidableEmptiness<B>,
+    B: Validate<Value = A::Value>,
+{
+    fn validate(&self, val: &A::Value) -> Result<(), ContainmentError> {
+        self.0.validate(val).and_then(|_| self.1.validate(val))
     }
+}
 
-    fn sup(&self) -> Option<OoC<A::Value>> {
-        match (self.0.sup(), self.1.sup()) {
-            (Some(left), Some(right)) => Some(clip_ooc(left, right, max_val)),
-            _ => None,
-        }
+/// The intersection with an interval is decided by first intersecting `B`
+/// with the interval, and then testing `A` against the result. For ordered
+/// operands this only compares bounds, so it applies to continuous spaces.
+impl<A, B, L, R> DecidableEmptiness<Interval<L, R>> for IntersectionPair<A, B>
+where
+    A: DecidableEmptiness<B> + DecidableEmptiness<IntersectionOf<B, Interval<L, R>>>,
+    B: Intersection<Interval<L, R>, Value = A::Value> + Clone,
+
+    L: bounds::Bound<Value = A::Value> + Clone,
+    R: bounds::Bound<Value = A::Value> + Clone,
+
+    A::Value: Clone,
+{
+    fn intersection_is_empty(&self, rhs: &Interval<L, R>) -> bool {
+        self.1.clone().intersect(rhs.clone()).map_or(true, |x| self.0.intersection_is_empty(&x))
     }
 }
 
+// For other right-hand sides, fall back to enumerating the elements of a
+// finite `A`.
+macro_rules! impl_finite_emptiness {
+    ($($rhs:ident<$($p:ident),+>),+) => {$(
+        impl<A, B, $($p),+> DecidableEmptiness<$rhs<$($p),+>> for IntersectionPair<A, B>
+        where
+            A: FiniteSpace + IterableSpace + DecidableEmptiness<B>,
+            B: Space<Value = A::Value>,
+            $($p: Space<Value = A::Value>,)+
+            $rhs<$($p),+>: Space<Value = A::Value>,
+        {
+            fn intersection_is_empty(&self, rhs: &$rhs<$($p),+>) -> bool {
+                !self.0.elements().any(|x| self.1.contains(&x) && rhs.contains(&x))
+            }
+        }
+    )+}
+}
+
+impl_finite_emptiness!(
+    UnionPair<X, Y>, IntersectionPair<X, Y>, DifferencePair<X, Y>, ComplementOf<S>, Option<S>
+);
+
 impl<A, B, Rhs> Union<Rhs> for IntersectionPair<A, B>
 where
-    A: Space,
+    A: DecidableEmptiness<B>,
     B: Space<Value = A::Value>,
     Rhs: Space<Value = A::Value>,
 {
@@ -84,15 +190,142 @@ where
 
 impl<A, B, Rhs> Intersection<Rhs> for IntersectionPair<A, B>
 where
-    A: Space,
+    A: DecidableEmptiness<B>,
     B: Space<Value = A::Value>,
     Rhs: Space<Value = A::Value>,
+
+    IntersectionPair<Self, Rhs>: Space,
 {
     type Output = IntersectionPair<Self, Rhs>;
 
     fn intersect(self, rhs: Rhs) -> Option<Self::Output> {
-        if self.0.is_empty() || self.1.is_empty() { return None; }
+        let intersect = IntersectionPair(self, rhs);
+
+        if intersect.is_empty() { None } else { Some(intersect) }
+    }
+}
+
+impl<A, B> Notation for IntersectionPair<A, B>
+where
+    A: Space + Notation,
+    B: Space<Value = A::Value> + Notation,
+{
+    fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+        fmt_binary(f, style, &self.0, &self.1, Precedence::Intersection)
+    }
+
+    fn precedence(&self) -> Precedence { Precedence::Intersection }
+}
+
+impl<A, B> fmt::Display for IntersectionPair<A, B>
+where
+    A: Space + Notation,
+    B: Space<Value = A::Value> + Notation,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.fmt_notation(f, Style::Unicode) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::intervals::Interval;
+    use super::*;
+
+    #[test]
+    fn test_ip_bounds() {
+        let pair = IntersectionPair(
+            Interval::left_open(-1.0),
+            Interval::closed_unchecked(0.0, 1.0),
+        );
+
+        assert_eq!(pair.inf().unwrap(), OoC::Closed(0.0));
+        assert_eq!(pair.sup().unwrap(), OoC::Closed(1.0));
+
+        let pair = IntersectionPair(
+            Interval::lcro_unchecked(0.0, 2.0),
+            Interval::lorc_unchecked(0.0, 2.0),
+        );
+
+        assert_eq!(pair.inf().unwrap(), OoC::Open(0.0));
+        assert_eq!(pair.sup().unwrap(), OoC::Open(2.0));
+    }
+
+    #[test]
+    fn test_ip_is_empty() {
+        let a = Interval::closed_unchecked(0.0, 1.0);
+
+        assert!(!IntersectionPair(a, Interval::closed_unchecked(1.0, 2.0)).is_empty());
+        assert!(IntersectionPair(a, Interval::lorc_unchecked(1.0, 2.0)).is_empty());
+        assert!(IntersectionPair(a, Interval::left_open(1.0)).is_empty());
+        assert!(!IntersectionPair(a, Interval::right_open(0.5)).is_empty());
+    }
+
+    #[test]
+    fn test_ip_is_empty_union() {
+        let a = Interval::closed_unchecked(1.25, 1.75);
+        let b = UnionPair(
+            Interval::closed_unchecked(0.0, 1.0),
+            Interval::closed_unchecked(2.0, 3.0),
+        );
+
+        assert!(IntersectionPair(b, a).is_empty());
+        assert!(b.intersect(a).is_none());
+        assert!(b.intersect(Interval::degenerate(2.0)).is_some());
+    }
+
+    #[test]
+    fn test_ip_is_empty_finite() {
+        let a = IntersectionPair(
+            Interval::closed_unchecked(0, 10),
+            Interval::closed_unchecked(5, 15),
+        );
+
+        assert!(!a.intersection_is_empty(&UnionPair(
+            Interval::closed_unchecked(-5, 0),
+            Interval::closed_unchecked(10, 12),
+        )));
+        assert!(a.intersection_is_empty(&UnionPair(
+            Interval::closed_unchecked(-5, 4),
+            Interval::closed_unchecked(11, 12),
+        )));
+    }
+
+    #[test]
+    fn test_ip_is_empty_continuous() {
+        let u = UnionPair(Interval::closed_unchecked(0.0, 1.0), Interval::closed_unchecked(2.0, 3.0));
+        let pair = u.intersect(Interval::closed_unchecked(0.5, 2.5)).unwrap();
+
+        assert!(!pair.intersection_is_empty(&Interval::closed_unchecked(0.75, 0.8)));
+        assert!(pair.intersection_is_empty(&Interval::open_unchecked(1.0, 2.0)));
+        assert!(pair.intersection_is_empty(&Interval::closed_unchecked(2.6, 3.0)));
+
+        assert!(pair.intersect(Interval::closed_unchecked(2.25, 4.0)).is_some());
+        assert!(pair.intersect(Interval::left_closed(2.75)).is_none());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let pair = IntersectionPair(Interval::closed_unchecked(0.0, 2.0), Interval::open_unchecked(1.0, 3.0));
+        let json = serde_json::to_string(&pair).unwrap();
+
+        assert_eq!(json, r#"[{"left":0.0,"right":2.0},{"left":1.0,"right":3.0}]"#);
+        assert_eq!(serde_json::from_str::<IntersectionPair<_, _>>(&json).unwrap(), pair);
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validate::{Side, Violation};
+
+        let pair = IntersectionPair(Interval::left_closed(0.0), Interval::right_open(1.0));
 
-        Some(IntersectionPair(self, rhs))
+        assert!(pair.validate(&0.5).is_ok());
+        assert!(matches!(
+            pair.validate(&-1.0).unwrap_err().violation,
+            Violation::Bound { side: Side::Inf, .. }
+        ));
+        assert!(matches!(
+            pair.validate(&1.0).unwrap_err().violation,
+            Violation::Bound { side: Side::Sup, .. }
+        ));
     }
 }
@@ -3,6 +3,7 @@ use crate::Space;
 
 type OoC<T> = crate::intervals::bounds::OpenOrClosed<T>;
 
+#[allow(clippy::upper_case_acronyms)]
 enum LRB<T> { Left(T), Both(T), Right(T), }
 
 impl<T> LRB<T> {
@@ -28,6 +29,84 @@ fn max_val<T: PartialOrd>(x: T, y: T) -> LRB<T> {
     if x < y { LRB::Right(y) } else if x == y { LRB::Both(x) } else { LRB::Left(x) }
 }
 
+/// Trait for scalar types whose open bounds can be tightened to closed ones.
+///
+/// Over a discrete type, an open bound is equivalent to the closed bound on
+/// the adjacent value; e.g. `(0, 3)` and `[1, 2]` contain the same integers.
+/// Rewriting bounds in this way allows convex spaces to be compared exactly.
+/// Continuous types leave their bounds unchanged.
+pub trait Tighten: PartialOrd + Sized {
+    /// Return the tightest lower bound equivalent to `bound`.
+    fn tighten_inf(bound: OoC<Self>) -> OoC<Self>;
+
+    /// Return the tightest upper bound equivalent to `bound`.
+    fn tighten_sup(bound: OoC<Self>) -> OoC<Self>;
+}
+
+macro_rules! impl_tighten_continuous {
+    ($($v:ty),+) => {$(
+        impl Tighten for $v {
+            fn tighten_inf(bound: OoC<$v>) -> OoC<$v> { bound }
+
+            fn tighten_sup(bound: OoC<$v>) -> OoC<$v> { bound }
+        }
+    )+}
+}
+
+impl_tighten_continuous!(f32, f64);
+
+// An open bound at the extreme of the type has no adjacent value within the
+// type, so it is left as is.
+macro_rules! impl_tighten_discrete {
+    ($($v:ty),+) => {$(
+        impl Tighten for $v {
+            fn tighten_inf(bound: OoC<$v>) -> OoC<$v> {
+                match bound {
+                    OoC::Open(x) => x.checked_add(1).map_or(OoC::Open(x), OoC::Closed),
+                    closed => closed,
+                }
+            }
+
+            fn tighten_sup(bound: OoC<$v>) -> OoC<$v> {
+                match bound {
+                    OoC::Open(x) => x.checked_sub(1).map_or(OoC::Open(x), OoC::Closed),
+                    closed => closed,
+                }
+            }
+        }
+    )+}
+}
+
+impl_tighten_discrete!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl Tighten for bool {
+    fn tighten_inf(bound: OoC<bool>) -> OoC<bool> {
+        match bound {
+            OoC::Open(false) => OoC::Closed(true),
+            bound => bound,
+        }
+    }
+
+    fn tighten_sup(bound: OoC<bool>) -> OoC<bool> {
+        match bound {
+            OoC::Open(true) => OoC::Closed(false),
+            bound => bound,
+        }
+    }
+}
+
+/// Returns true iff no value lies within the given (tightened) limits.
+fn limits_empty<T: PartialOrd>(inf: Option<&OoC<T>>, sup: Option<&OoC<T>>) -> bool {
+    use crate::intervals::bounds::OpenOrClosed::*;
+
+    match (inf, sup) {
+        (Some(Closed(l)), Some(Closed(r))) => l > r,
+        (Some(Open(l)), Some(Open(r))) | (Some(Open(l)), Some(Closed(r))) |
+        (Some(Closed(l)), Some(Open(r))) => l >= r,
+        _ => false,
+    }
+}
+
 /// Trait for types that have a well-defined closure.
 pub trait Closure: Space {
     type Output: Space<Value = Self::Value>;
@@ -41,4 +120,15 @@ mod union;
 pub use self::union::{Union, UnionOf, UnionClosureOf, UnionPair};
 
 mod intersection;
-pub use self::intersection::{Intersection, IntersectionOf, IntersectionPair};
+pub use self::intersection::{DecidableEmptiness, Intersection, IntersectionOf, IntersectionPair};
+pub(crate) use self::intersection::convex_disjoint;
+
+mod relations;
+pub use self::relations::SetRelations;
+pub(crate) use self::relations::convex_subset;
+
+mod complement;
+pub use self::complement::{Complement, ComplementOf};
+
+mod difference;
+pub use self::difference::{Difference, DifferenceOf, DifferencePair};
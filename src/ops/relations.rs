@@ -0,0 +1,54 @@
+use crate::{Space, OrderedSpace};
+use super::{limits_empty, Tighten, intersection::{pinch_inf, pinch_sup}};
+
+/// Trait for pairs of spaces whose set relations can be decided exactly.
+///
+/// ```
+/// # extern crate spaces;
+/// # use spaces::{ops::SetRelations, intervals::Interval};
+/// let a = Interval::closed_unchecked(0.0, 1.0);
+///
+/// assert!(a.is_subset_of(&Interval::left_closed(0.0)));
+/// assert!(!a.is_subset_of(&Interval::left_open(0.0)));
+/// assert!(a.is_disjoint_from(&Interval::left_open(1.0)));
+/// ```
+pub trait SetRelations<Rhs: Space<Value = Self::Value> = Self>: Space {
+    /// Returns true iff every element of `self` is also an element of `rhs`.
+    fn is_subset_of(&self, rhs: &Rhs) -> bool;
+
+    /// Returns true iff every element of `rhs` is also an element of `self`.
+    fn is_superset_of(&self, rhs: &Rhs) -> bool;
+
+    /// Returns true iff `self` and `rhs` have no elements in common.
+    fn is_disjoint_from(&self, rhs: &Rhs) -> bool;
+
+    /// Returns true iff `self` and `rhs` contain exactly the same elements.
+    fn set_eq(&self, rhs: &Rhs) -> bool { self.is_subset_of(rhs) && self.is_superset_of(rhs) }
+}
+
+/// Returns true iff the convex ordered space `a` is a subset of the convex
+/// ordered space `b`.
+///
+/// A missing infimum/supremum is treated as an unbounded limit. Bounds are
+/// tightened before comparison, so the result is exact for discrete as well
+/// as continuous values.
+pub(crate) fn convex_subset<A, B>(a: &A, b: &B) -> bool
+where
+    A: OrderedSpace,
+    B: OrderedSpace<Value = A::Value>,
+
+    A::Value: Tighten + Clone,
+{
+    if a.is_empty() { return true; }
+
+    let (inf, sup) = (a.inf().map(Tighten::tighten_inf), a.sup().map(Tighten::tighten_sup));
+
+    if limits_empty(inf.as_ref(), sup.as_ref()) { return true; }
+    if b.is_empty() { return false; }
+
+    let (b_inf, b_sup) = (b.inf().map(Tighten::tighten_inf), b.sup().map(Tighten::tighten_sup));
+
+    if limits_empty(b_inf.as_ref(), b_sup.as_ref()) { return false; }
+
+    pinch_inf(inf.clone(), b_inf) == inf && pinch_sup(sup.clone(), b_sup) == sup
+}
@@ -1,5 +1,14 @@
-use crate::{OrderedSpace, Space};
-use super::{OoC, LRB, min_val, max_val, Intersection, IntersectionPair, Closure, ClosureOf};
+use crate::{
+    Measure, OrderedSpace, Project, Space, Validate,
+    notation::{fmt_binary, Notation, Precedence, Style},
+    project::{Metric, OpenBounds},
+    validate::{ContainmentError, Violation},
+};
+use super::{
+    OoC, LRB, min_val, max_val,
+    Closure, ClosureOf, DecidableEmptiness, Intersection, IntersectionOf, IntersectionPair,
+};
+use std::fmt;
 
 fn clip_ooc<T: PartialOrd>(x: OoC<T>, y: OoC<T>, cmp: impl Fn(T, T) -> LRB<T>) -> OoC<T> {
     use crate::intervals::bounds::OpenOrClosed::*;
@@ -36,6 +45,11 @@ pub type UnionClosureOf<S, T> = ClosureOf<UnionOf<S, T>>;
 
 /// Type representing the union of two arbitrary spaces.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
 pub struct UnionPair<A: Space, B: Space<Value = A::Value>>(pub A, pub B);
 
 impl<A, B> Space for UnionPair<A, B>
@@ -72,6 +86,114 @@ where
     }
 }
 
+impl<A, B> Measure for UnionPair<A, B>
+where
+    A: Measure + Intersection<B> + Clone,
+    B: Measure<Value = A::Value> + Clone,
+
+    IntersectionOf<A, B>: Measure,
+{
+    fn measure(&self) -> f64 {
+        let (a, b) = (self.0.measure(), self.1.measure());
+
+        if a.is_infinite() || b.is_infinite() { return f64::INFINITY; }
+
+        // Inclusion-exclusion; the overlap is zero for disjoint components.
+        let overlap = self.0.clone().intersect(self.1.clone()).map_or(0.0, |i| i.measure());
+
+        a + b - overlap
+    }
+}
+
+impl<A, B> Project for UnionPair<A, B>
+where
+    A: Project,
+    B: Project<Value = A::Value>,
+
+    A::Value: Clone + Metric,
+{
+    fn project_with(&self, val: A::Value, strategy: OpenBounds) -> A::Value {
+        if self.1.is_empty() {
+            return self.0.project_with(val, strategy);
+        } else if self.0.is_empty() {
+            return self.1.project_with(val, strategy);
+        }
+
+        let left = self.0.project_with(val.clone(), strategy);
+        let right = self.1.project_with(val.clone(), strategy);
+
+        if left.distance(&val) <= right.distance(&val) { left } else { right }
+    }
+}
+
+impl<A, B> Validate for UnionPair<A, B>
+where
+    A: Validate,
+    B: Validate<Value = A::Value>,
+{
+    fn validate(&self, val: &A::Value) -> Result<(), ContainmentError> {
+        match (self.0.validate(val), self.1.validate(val)) {
+            (Err(a), Err(b)) => Err(ContainmentError::new(Violation::Union(Box::new(a), Box::new(b)))),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<A, B> crate::Sample for UnionPair<A, B>
+where
+    A: crate::Sample + Measure,
+    B: crate::Sample<Value = A::Value> + Measure,
+{
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> A::Value {
+        assert!(!self.is_empty(), "Cannot sample from an empty space.");
+
+        let m_left = self.0.measure();
+        let m_total = m_left + self.1.measure();
+
+        // Null components (e.g. degenerate intervals) can only be weighted by
+        // their non-emptiness.
+        let p_left = if m_total > 0.0 {
+            m_left / m_total
+        } else if self.1.is_empty() {
+            1.0
+        } else if self.0.is_empty() {
+            0.0
+        } else {
+            0.5
+        };
+
+        // Elements lying in both spaces would be drawn twice as often, so they
+        // are rejected half of the time to keep the distribution uniform.
+        loop {
+            let (x, in_both) = if rng.gen_bool(p_left) {
+                let x = self.0.sample(rng);
+                let in_both = self.1.contains(&x);
+
+                (x, in_both)
+            } else {
+                let x = self.1.sample(rng);
+                let in_both = self.0.contains(&x);
+
+                (x, in_both)
+            };
+
+            if !in_both || rng.gen_bool(0.5) { break x; }
+        }
+    }
+}
+
+impl<A, B, C> DecidableEmptiness<C> for UnionPair<A, B>
+where
+    A: DecidableEmptiness<C>,
+    B: DecidableEmptiness<C, Value = A::Value>,
+    C: Space<Value = A::Value>,
+{
+    fn intersection_is_empty(&self, rhs: &C) -> bool {
+        self.0.intersection_is_empty(rhs) && self.1.intersection_is_empty(rhs)
+    }
+}
+
 impl<A, B, C> Union<C> for UnionPair<A, B>
 where
     A: Space,
@@ -85,8 +207,8 @@ where
 
 impl<A, B, C> Intersection<C> for UnionPair<A, B>
 where
-    A: Space,
-    B: Space<Value = A::Value>,
+    A: DecidableEmptiness<C>,
+    B: DecidableEmptiness<C, Value = A::Value>,
     C: Space<Value = A::Value>,
 {
     type Output = IntersectionPair<Self, C>;
@@ -98,14 +220,24 @@ where
     }
 }
 
-impl<S, T> std::fmt::Display for UnionPair<S, T>
+impl<S, T> Notation for UnionPair<S, T>
 where
-    S: Space + std::fmt::Display,
-    T: Space<Value = S::Value> + std::fmt::Display,
+    S: Space + Notation,
+    T: Space<Value = S::Value> + Notation,
 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} \u{222A} {}", self.0, self.1)
+    fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+        fmt_binary(f, style, &self.0, &self.1, Precedence::Union)
     }
+
+    fn precedence(&self) -> Precedence { Precedence::Union }
+}
+
+impl<S, T> fmt::Display for UnionPair<S, T>
+where
+    S: Space + Notation,
+    T: Space<Value = S::Value> + Notation,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.fmt_notation(f, Style::Unicode) }
 }
 
 #[cfg(test)]
@@ -151,6 +283,63 @@ mod tests {
         assert!(pair.inf().is_none());
     }
 
+    #[test]
+    fn test_project() {
+        let pair = UnionPair(
+            Interval::closed_unchecked(0.0, 1.0),
+            Interval::closed_unchecked(2.0, 3.0)
+        );
+
+        assert_eq!(pair.project(-1.0), 0.0);
+        assert_eq!(pair.project(0.5), 0.5);
+        assert_eq!(pair.project(1.4), 1.0);
+        assert_eq!(pair.project(1.6), 2.0);
+        assert_eq!(pair.project(4.0), 3.0);
+    }
+
+    #[test]
+    fn test_measure() {
+        let disjoint = UnionPair(
+            Interval::closed_unchecked(0.0, 1.0),
+            Interval::closed_unchecked(2.0, 3.5)
+        );
+        let overlapping = UnionPair(
+            Interval::closed_unchecked(0, 2),
+            Interval::closed_unchecked(2, 3)
+        );
+
+        assert_eq!(disjoint.measure(), 2.5);
+        assert_eq!(overlapping.measure(), 4.0);
+        assert_eq!(UnionPair(Interval::left_closed(0.0), Interval::unit()).measure(), f64::INFINITY);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample() {
+        use crate::Sample;
+
+        let mut rng = rand::thread_rng();
+        let pair = UnionPair(
+            Interval::closed_unchecked(0, 2),
+            Interval::closed_unchecked(2, 3)
+        );
+        let mut counts = [0usize; 4];
+
+        for _ in 0..4000 {
+            counts[pair.sample(&mut rng)] += 1;
+        }
+
+        assert!(counts.iter().all(|&c| c > 800 && c < 1200));
+
+        let pair = UnionPair(
+            Interval::closed_unchecked(0.0, 1.0),
+            Interval::closed_unchecked(2.0, 5.0)
+        );
+        let n_left = (0..4000).filter(|_| pair.sample(&mut rng) < 1.5).count();
+
+        assert!(n_left > 800 && n_left < 1200);
+    }
+
     #[test]
     fn test_up_both() {
         let pair = UnionPair(
@@ -169,4 +358,28 @@ mod tests {
         assert_eq!(pair.inf().unwrap(), OoC::Open(-1.0));
         assert_eq!(pair.sup().unwrap(), OoC::Closed(1.0));
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let pair = UnionPair(Interval::closed_unchecked(0.0, 1.0), Interval::open_unchecked(2.0, 3.0));
+        let json = serde_json::to_string(&pair).unwrap();
+
+        assert_eq!(json, r#"[{"left":0.0,"right":1.0},{"left":2.0,"right":3.0}]"#);
+        assert_eq!(serde_json::from_str::<UnionPair<_, _>>(&json).unwrap(), pair);
+    }
+
+    #[test]
+    fn test_validate() {
+        let pair = UnionPair(Interval::closed_unchecked(0, 1), Interval::closed_unchecked(3, 4));
+
+        assert!(pair.validate(&0).is_ok());
+        assert!(pair.validate(&4).is_ok());
+
+        let err = pair.validate(&2).unwrap_err();
+
+        assert_eq!(err.to_string(), "no branch of the union contains the value (\
+            value 2 lies above the closed upper bound 1; \
+            value 2 lies below the closed lower bound 3)");
+    }
 }
@@ -1,9 +1,12 @@
 use crate::{
-    FiniteSpace, OrderedSpace, Space, IterableSpace,
+    FiniteSpace, Space, IterableSpace,
     intervals::bounds::OpenOrClosed,
-    ops::UnionPair,
+    notation::{self, Notation, Precedence, Style},
+    ops::{DecidableEmptiness, SetRelations, UnionPair},
     prelude::*,
+    validate::{ContainmentError, Violation},
 };
+use std::fmt;
 
 pub struct OptionIter<S: IterableSpace>(Option<S::ElemIter>);
 
@@ -41,6 +44,10 @@ impl<S: FiniteSpace> FiniteSpace for Option<S> {
     fn cardinality(&self) -> usize { self.as_ref().map_or(0, |s| s.cardinality()) }
 }
 
+impl<S: Measure> Measure for Option<S> {
+    fn measure(&self) -> f64 { self.as_ref().map_or(0.0, |s| s.measure()) }
+}
+
 impl<S: IterableSpace> IterableSpace for Option<S> {
     type ElemIter = OptionIter<S>;
 
@@ -49,6 +56,50 @@ impl<S: IterableSpace> IterableSpace for Option<S> {
     }
 }
 
+impl<S: Validate> Validate for Option<S> {
+    fn validate(&self, val: &Self::Value) -> Result<(), ContainmentError> {
+        match self {
+            Some(s) => s.validate(val),
+            None => Err(ContainmentError::new(Violation::Empty)),
+        }
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<S: Sample> Sample for Option<S> {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Self::Value {
+        self.as_ref().expect("Cannot sample from an empty space.").sample(rng)
+    }
+}
+
+impl<S, T> DecidableEmptiness<T> for Option<S>
+where
+    S: DecidableEmptiness<T>,
+    T: Space<Value = S::Value>,
+{
+    fn intersection_is_empty(&self, rhs: &T) -> bool {
+        self.as_ref().map_or(true, |s| s.intersection_is_empty(rhs))
+    }
+}
+
+impl<S, T> SetRelations<T> for Option<S>
+where
+    S: SetRelations<T>,
+    T: Space<Value = S::Value>,
+{
+    fn is_subset_of(&self, rhs: &T) -> bool {
+        self.as_ref().map_or(true, |s| s.is_subset_of(rhs))
+    }
+
+    fn is_superset_of(&self, rhs: &T) -> bool {
+        self.as_ref().map_or_else(|| rhs.is_empty(), |s| s.is_superset_of(rhs))
+    }
+
+    fn is_disjoint_from(&self, rhs: &T) -> bool {
+        self.as_ref().map_or(true, |s| s.is_disjoint_from(rhs))
+    }
+}
+
 impl<S: Closure> Closure for Option<S> {
     type Output = Option<S::Output>;
 
@@ -71,6 +122,17 @@ impl<S: Intersection<T>, T: Space<Value = S::Value>> Intersection<T> for Option<
     }
 }
 
+impl<S: Notation> Notation for Option<S> {
+    fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+        match self {
+            Some(s) => s.fmt_notation(f, style),
+            None => notation::fmt_empty(f, style),
+        }
+    }
+
+    fn precedence(&self) -> Precedence { self.as_ref().map_or(Precedence::Atom, |s| s.precedence()) }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -85,6 +147,29 @@ mod tests {
         assert!(!Some(Interval::degenerate(0.0)).is_empty());
     }
 
+    #[test]
+    fn test_set_relations() {
+        let e: Option<crate::intervals::Closed<f64>> = None;
+        let a = Some(Interval::closed_unchecked(0.0f64, 1.0));
+
+        assert!(e.is_subset_of(&Interval::open_unchecked(0.0, 1.0)));
+        assert!(e.is_disjoint_from(&Interval::open_unchecked(0.0, 1.0)));
+        assert!(!e.is_superset_of(&Interval::open_unchecked(0.0, 1.0)));
+        assert!(e.set_eq(&Interval::open_unchecked(1.0, 0.0)));
+
+        assert!(a.is_subset_of(&Interval::left_closed(0.0)));
+        assert!(a.is_superset_of(&Interval::open_unchecked(0.0, 1.0)));
+        assert!(!a.is_disjoint_from(&Interval::degenerate(1.0)));
+    }
+
+    #[test]
+    fn test_measure() {
+        let e: Option<crate::intervals::Closed<f64>> = None;
+
+        assert_eq!(e.measure(), 0.0);
+        assert_eq!(Some(Interval::closed_unchecked(0.0, 2.0)).measure(), 2.0);
+    }
+
     #[test]
     fn test_union() {
         let e: Option<crate::real::PositiveReals<f64>> = None;
@@ -110,4 +195,14 @@ mod tests {
         assert!(a.is_none());
         assert!(b.is_none());
     }
+
+    #[test]
+    fn test_validate() {
+        use crate::validate::Violation;
+
+        let s: Option<crate::discrete::Binary> = None;
+
+        assert_eq!(s.validate(&true).unwrap_err().violation, Violation::Empty);
+        assert!(Some(Interval::closed_unchecked(false, true)).validate(&true).is_ok());
+    }
 }
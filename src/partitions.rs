@@ -1,16 +1,22 @@
 use crate::{
     intervals::{partitions::{self, Partition}, bounds::OpenOrClosed},
+    notation::{self, Notation, Style},
+    ops::{DecidableEmptiness, Intersection, IntersectionPair, SetRelations},
     FiniteSpace,
+    IndexedSpace,
     OrderedSpace,
     Space,
     IterableSpace,
+    Validate,
+    validate::{self, ContainmentError},
+    encode::{self, Encode},
 };
-use std::ops::Range;
+use std::{fmt, ops::Range};
 
 impl<V> Space for partitions::Uniform<V> {
     type Value = usize;
 
-    fn is_empty(&self) -> bool { self.size > 0 }
+    fn is_empty(&self) -> bool { self.size == 0 }
 
     fn contains(&self, value: &usize) -> bool { value >= &0 && value < &self.size }
 }
@@ -25,20 +31,87 @@ impl<V> OrderedSpace for partitions::Uniform<V> {
     }
 }
 
+impl<V> Validate for partitions::Uniform<V> {
+    fn validate(&self, value: &usize) -> Result<(), ContainmentError> {
+        validate::check_bounds(Some(OpenOrClosed::Closed(0)), Some(OpenOrClosed::Open(self.size)), value)
+    }
+}
+
 impl<V> FiniteSpace for partitions::Uniform<V> {
     fn cardinality(&self) -> usize { self.size }
 }
 
+impl<V> Encode for partitions::Uniform<V> {
+    fn encoding_len(&self) -> usize { self.cardinality() }
+
+    fn encode_into(&self, value: &usize, out: &mut [f32]) { encode::one_hot_into(self, value, out) }
+
+    fn decode(&self, features: &[f32]) -> Option<usize> { encode::argmax_decode(self, features) }
+}
+
+impl<V> IndexedSpace for partitions::Uniform<V> {
+    fn index_of(&self, value: &usize) -> Option<usize> { Some(*value).filter(|v| self.contains(v)) }
+
+    fn from_index(&self, index: usize) -> Option<usize> { self.index_of(&index) }
+}
+
+impl<V> crate::Measure for partitions::Uniform<V> {
+    fn measure(&self) -> f64 { self.size as f64 }
+}
+
+impl<V, W> DecidableEmptiness<partitions::Uniform<W>> for partitions::Uniform<V> {
+    fn intersection_is_empty(&self, rhs: &partitions::Uniform<W>) -> bool {
+        crate::ops::convex_disjoint(self, rhs)
+    }
+}
+
+// Bin indices are intersected as sets; the result is only decided to be
+// non-empty, since it need not itself be a partition.
+impl<V, W> Intersection<partitions::Uniform<W>> for partitions::Uniform<V> {
+    type Output = IntersectionPair<Self, partitions::Uniform<W>>;
+
+    fn intersect(self, rhs: partitions::Uniform<W>) -> Option<Self::Output> {
+        let intersect = IntersectionPair(self, rhs);
+
+        if intersect.is_empty() { None } else { Some(intersect) }
+    }
+}
+
+/// Set relations between uniform partitions.
+///
+/// The bin at a given index only covers the same region of the underlying
+/// range when both partitions share the same bounds and number of bins. A
+/// non-empty partition is therefore only a subset of another partition over
+/// the same grid.
+impl<V: PartialEq> SetRelations<partitions::Uniform<V>> for partitions::Uniform<V> {
+    fn is_subset_of(&self, rhs: &partitions::Uniform<V>) -> bool {
+        self.is_empty() || self == rhs
+    }
+
+    fn is_superset_of(&self, rhs: &partitions::Uniform<V>) -> bool {
+        rhs.is_subset_of(self)
+    }
+
+    fn is_disjoint_from(&self, rhs: &partitions::Uniform<V>) -> bool {
+        crate::ops::convex_disjoint(self, rhs)
+    }
+}
+
 impl<V> IterableSpace for partitions::Uniform<V> {
     type ElemIter = Range<usize>;
 
     fn elements(&self) -> Self::ElemIter { 0..self.size }
 }
 
+#[cfg(feature = "rand")]
+impl<V> crate::Sample for partitions::Uniform<V> {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> usize { rng.gen_range(0..self.size) }
+}
+
 impl<const N: usize, V: PartialOrd + Clone> Space for partitions::Declarative<N, V> {
     type Value = usize;
 
-    fn is_empty(&self) -> bool { self.len() > 0 }
+    fn is_empty(&self) -> bool { self.len() == 0 }
 
     fn contains(&self, value: &usize) -> bool { value >= &0 && value < &self.len() }
 }
@@ -53,12 +126,253 @@ impl<const N: usize, V: PartialOrd + Clone> OrderedSpace for partitions::Declara
     }
 }
 
+impl<const N: usize, V: PartialOrd + Clone> Validate for partitions::Declarative<N, V> {
+    fn validate(&self, value: &usize) -> Result<(), ContainmentError> {
+        validate::check_bounds(Some(OpenOrClosed::Closed(0)), Some(OpenOrClosed::Open(self.len())), value)
+    }
+}
+
 impl<const N: usize, V: PartialOrd + Clone> FiniteSpace for partitions::Declarative<N, V> {
     fn cardinality(&self) -> usize { self.len() }
 }
 
+impl<const N: usize, V: PartialOrd + Clone> Encode for partitions::Declarative<N, V> {
+    fn encoding_len(&self) -> usize { self.cardinality() }
+
+    fn encode_into(&self, value: &usize, out: &mut [f32]) { encode::one_hot_into(self, value, out) }
+
+    fn decode(&self, features: &[f32]) -> Option<usize> { encode::argmax_decode(self, features) }
+}
+
+impl<const N: usize, V: PartialOrd + Clone> IndexedSpace for partitions::Declarative<N, V> {
+    fn index_of(&self, value: &usize) -> Option<usize> { Some(*value).filter(|v| self.contains(v)) }
+
+    fn from_index(&self, index: usize) -> Option<usize> { self.index_of(&index) }
+}
+
+impl<const N: usize, V: PartialOrd + Clone> crate::Measure for partitions::Declarative<N, V> {
+    fn measure(&self) -> f64 { self.len() as f64 }
+}
+
+impl<const N: usize, const M: usize, V, W> DecidableEmptiness<partitions::Declarative<M, W>>
+    for partitions::Declarative<N, V>
+where
+    V: PartialOrd + Clone,
+    W: PartialOrd + Clone,
+{
+    fn intersection_is_empty(&self, rhs: &partitions::Declarative<M, W>) -> bool {
+        crate::ops::convex_disjoint(self, rhs)
+    }
+}
+
+impl<const N: usize, const M: usize, V, W> Intersection<partitions::Declarative<M, W>>
+    for partitions::Declarative<N, V>
+where
+    V: PartialOrd + Clone,
+    W: PartialOrd + Clone,
+{
+    type Output = IntersectionPair<Self, partitions::Declarative<M, W>>;
+
+    fn intersect(self, rhs: partitions::Declarative<M, W>) -> Option<Self::Output> {
+        let intersect = IntersectionPair(self, rhs);
+
+        if intersect.is_empty() { None } else { Some(intersect) }
+    }
+}
+
+/// Set relations between declarative partitions.
+///
+/// As with uniform partitions, a non-empty partition is only a subset of
+/// another partition with exactly the same bounds.
+impl<const N: usize, const M: usize, V> SetRelations<partitions::Declarative<M, V>>
+    for partitions::Declarative<N, V>
+where
+    V: PartialOrd + Clone,
+{
+    fn is_subset_of(&self, rhs: &partitions::Declarative<M, V>) -> bool {
+        self.is_empty() || self.0.as_slice() == rhs.0.as_slice()
+    }
+
+    fn is_superset_of(&self, rhs: &partitions::Declarative<M, V>) -> bool {
+        rhs.is_subset_of(self)
+    }
+
+    fn is_disjoint_from(&self, rhs: &partitions::Declarative<M, V>) -> bool {
+        crate::ops::convex_disjoint(self, rhs)
+    }
+}
+
 impl<const N: usize, V: PartialOrd + Clone> IterableSpace for partitions::Declarative<N, V> {
     type ElemIter = Range<usize>;
 
-    fn elements(&self) -> Self::ElemIter { 0..N }
+    fn elements(&self) -> Self::ElemIter { 0..self.len() }
+}
+
+#[cfg(feature = "rand")]
+impl<const N: usize, V: PartialOrd + Clone> crate::Sample for partitions::Declarative<N, V> {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> usize { rng.gen_range(0..self.len()) }
+}
+
+/// Serde helpers for `intervals::partitions::Declarative`.
+///
+/// `Declarative` is defined upstream without serde support, so it cannot
+/// implement `Serialize`/`Deserialize` directly. Use this module with
+/// `#[serde(with = "spaces::serde_declarative")]` on fields of that type
+/// instead. The partition is encoded as the JSON array of its bounds, and the
+/// bounds are checked to be non-decreasing on the way back in.
+#[cfg(feature = "serde")]
+pub mod serde_declarative {
+    use crate::intervals::partitions::Declarative;
+    use serde_crate::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+    use std::convert::TryInto;
+
+    /// Serialise a `Declarative` partition as the sequence of its bounds.
+    pub fn serialize<const N: usize, V, S>(
+        partition: &Declarative<N, V>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        V: Serialize,
+        S: Serializer,
+    {
+        partition.0.as_slice().serialize(serializer)
+    }
+
+    /// Deserialise a `Declarative` partition from the sequence of its bounds.
+    pub fn deserialize<'de, const N: usize, V, D>(
+        deserializer: D,
+    ) -> Result<Declarative<N, V>, D::Error>
+    where
+        V: PartialOrd + Deserialize<'de>,
+        D: Deserializer<'de>,
+    {
+        let bounds: Vec<V> = Vec::deserialize(deserializer)?;
+        let len = bounds.len();
+        let bounds: [V; N] = bounds.try_into().map_err(|_| D::Error::invalid_length(
+            len, &format!("{} partition bounds", N).as_str()
+        ))?;
+
+        Declarative::new(bounds).map_err(|_| D::Error::custom("partition bounds must be non-decreasing"))
+    }
+}
+
+impl<V> Notation for partitions::Uniform<V> {
+    fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+        notation::fmt_range(f, style, self.size)
+    }
+}
+
+impl<const N: usize, V: PartialOrd + Clone> Notation for partitions::Declarative<N, V> {
+    fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+        notation::fmt_range(f, style, self.cardinality())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_uniform_is_empty() {
+        let p = partitions::Uniform { size: 0, left: 0.0, right: 1.0 };
+        let q = partitions::Uniform { size: 5, left: 0.0, right: 1.0 };
+
+        assert!(p.is_empty());
+        assert!(!q.is_empty());
+
+        assert!(p.intersection_is_empty(&q));
+        assert!(!q.intersection_is_empty(&q));
+    }
+
+    #[test]
+    fn test_uniform_intersect() {
+        let p = partitions::Uniform { size: 0, left: 0.0, right: 1.0 };
+        let q = partitions::Uniform { size: 5, left: 0.0, right: 1.0 };
+        let r = partitions::Uniform { size: 3, left: 0.0, right: 1.0 };
+
+        assert!(p.intersect(q).is_none());
+        assert!(q.intersect(r).unwrap().contains(&2));
+        assert!(!q.intersect(r).unwrap().contains(&3));
+
+        let boxed = [q, r].intersect([r, q]).unwrap();
+
+        assert!(boxed.contains(&[2, 1]));
+        assert!(!boxed.contains(&[3, 1]));
+    }
+
+    #[test]
+    fn test_uniform_set_relations() {
+        let p = partitions::Uniform { size: 0, left: 0.0, right: 1.0 };
+        let q = partitions::Uniform { size: 5, left: 0.0, right: 1.0 };
+        let r = partitions::Uniform { size: 3, left: 0.0, right: 1.0 };
+
+        assert!(p.is_subset_of(&q));
+        assert!(q.is_superset_of(&p));
+        assert!(!r.is_subset_of(&q));
+        assert!(!q.is_superset_of(&r));
+        assert!(p.is_disjoint_from(&q));
+        assert!(!q.is_disjoint_from(&r));
+        assert_eq!(crate::Measure::measure(&q), 5.0);
+        assert!(q.set_eq(&partitions::Uniform { size: 5, left: 0.0, right: 1.0 }));
+        assert!(!q.set_eq(&partitions::Uniform { size: 5, left: 2.0, right: 3.0 }));
+        assert!(p.set_eq(&partitions::Uniform { size: 0, left: 2.0, right: 3.0 }));
+    }
+
+    #[test]
+    fn test_declarative_elements() {
+        let p = partitions::Declarative::new([0.0, 0.5, 1.0, 2.0]).unwrap();
+
+        assert_eq!(p.elements().count(), p.cardinality());
+        assert!(p.elements().enumerate().all(|(i, x)| p.index_of(&x) == Some(i)));
+    }
+
+    #[test]
+    fn test_declarative_set_relations() {
+        let p = partitions::Declarative::new([0.0, 0.5, 2.0]).unwrap();
+        let q = partitions::Declarative::new([0.0, 0.5, 1.0]).unwrap();
+
+        assert!(p.set_eq(&p));
+        assert!(!p.is_subset_of(&q));
+        assert!(!p.is_superset_of(&q));
+        assert!(!p.is_disjoint_from(&q));
+        assert!(!partitions::Declarative::new([0.0, 1.0, 2.0]).unwrap().is_subset_of(&p));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        #[derive(Debug, PartialEq, Serialize, Deserialize)]
+        #[serde(crate = "serde_crate")]
+        struct Wrapper {
+            uniform: partitions::Uniform<f64>,
+
+            #[serde(with = "super::serde_declarative")]
+            declarative: partitions::Declarative<3, f64>,
+        }
+
+        let w = Wrapper {
+            uniform: partitions::Uniform { size: 5, left: 0.0, right: 1.0 },
+            declarative: partitions::Declarative::new([0.0, 0.5, 2.0]).unwrap(),
+        };
+        let json = serde_json::to_string(&w).unwrap();
+
+        assert_eq!(json, r#"{"uniform":{"size":5,"left":0.0,"right":1.0},"declarative":[0.0,0.5,2.0]}"#);
+        assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), w);
+
+        assert!(serde_json::from_str::<Wrapper>(
+            r#"{"uniform":{"size":5,"left":0.0,"right":1.0},"declarative":[0.0,2.0,0.5]}"#
+        ).is_err());
+        assert!(serde_json::from_str::<Wrapper>(
+            r#"{"uniform":{"size":5,"left":0.0,"right":1.0},"declarative":[0.0,2.0]}"#
+        ).is_err());
+    }
+
+    #[test]
+    fn test_encode() {
+        let p = partitions::Uniform { size: 4, left: 0.0, right: 1.0 };
+
+        assert_eq!(p.encode(&2), vec![0.0, 0.0, 1.0, 0.0]);
+        assert_eq!(p.decode(&[0.0, 0.0, 1.0, 0.0]), Some(2));
+        assert_eq!(p.index_of(&4), None);
+    }
 }
@@ -0,0 +1,212 @@
+//! Module for utilities supporting the projection of values onto spaces.
+
+/// Strategy for projecting values onto open bounds.
+///
+/// An open bound is not itself an element of the space, so values lying
+/// beyond it must be mapped to a nearby interior point instead.
+#[derive(Copy, Clone, PartialEq, Debug, Default)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub enum OpenBounds {
+    /// Use the next representable value inside the bound.
+    #[default]
+    NextRepresentable,
+
+    /// Offset the bound by a fixed amount into the interior.
+    ///
+    /// If the offset would carry the value past the opposite bound, the
+    /// midpoint of the interval is used instead.
+    ///
+    /// __Note__: integer types ignore the offset and always use the next
+    /// representable value.
+    Epsilon(f64),
+}
+
+/// Trait for scalar types that can be nudged away from an open bound.
+pub trait Nudge: PartialOrd + Sized {
+    /// Return a value strictly greater than `self`.
+    ///
+    /// __Note__: at the greatest value of the type there is nothing greater,
+    /// so `self` is returned unchanged.
+    fn nudge_up(self, strategy: OpenBounds) -> Self;
+
+    /// Return a value strictly less than `self`.
+    ///
+    /// __Note__: at the least value of the type there is nothing smaller, so
+    /// `self` is returned unchanged.
+    fn nudge_down(self, strategy: OpenBounds) -> Self;
+
+    /// Return a value lying halfway between `self` and `other`, rounded
+    /// towards the smaller of the two.
+    fn halfway_to(self, other: Self) -> Self;
+}
+
+macro_rules! impl_nudge_float {
+    ($($t:ty),+) => {$(
+        impl Nudge for $t {
+            fn nudge_up(self, strategy: OpenBounds) -> $t {
+                match strategy {
+                    OpenBounds::NextRepresentable => {
+                        if self.is_nan() || self == <$t>::INFINITY {
+                            self
+                        } else if self == 0.0 {
+                            // Both signed zeros step to the smallest subnormal.
+                            <$t>::from_bits(1)
+                        } else if self > 0.0 {
+                            <$t>::from_bits(self.to_bits() + 1)
+                        } else {
+                            <$t>::from_bits(self.to_bits() - 1)
+                        }
+                    },
+                    OpenBounds::Epsilon(eps) => self + eps as $t,
+                }
+            }
+
+            fn nudge_down(self, strategy: OpenBounds) -> $t {
+                match strategy {
+                    OpenBounds::NextRepresentable => -(-self).nudge_up(strategy),
+                    OpenBounds::Epsilon(eps) => self - eps as $t,
+                }
+            }
+
+            fn halfway_to(self, other: $t) -> $t { self / 2.0 + other / 2.0 }
+        }
+    )+}
+}
+
+impl_nudge_float!(f32, f64);
+
+macro_rules! impl_nudge_int {
+    ($($t:ty => $u:ty),+) => {$(
+        impl Nudge for $t {
+            fn nudge_up(self, _: OpenBounds) -> $t { self.saturating_add(1) }
+
+            fn nudge_down(self, _: OpenBounds) -> $t { self.saturating_sub(1) }
+
+            fn halfway_to(self, other: $t) -> $t {
+                let (lo, hi) = if self < other { (self, other) } else { (other, self) };
+
+                // The gap always fits in the unsigned counterpart of the type.
+                lo.wrapping_add(((hi as $u).wrapping_sub(lo as $u) / 2) as $t)
+            }
+        }
+    )+}
+}
+
+impl_nudge_int!(
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize
+);
+
+impl Nudge for bool {
+    fn nudge_up(self, _: OpenBounds) -> bool { true }
+
+    fn nudge_down(self, _: OpenBounds) -> bool { false }
+
+    fn halfway_to(self, other: bool) -> bool { self && other }
+}
+
+/// Trait for values with a well-defined distance between them.
+///
+/// This is used to choose between candidate projections, such as for the
+/// components of a `UnionPair`.
+pub trait Metric {
+    /// Return the distance between `self` and `other`.
+    fn distance(&self, other: &Self) -> f64;
+}
+
+macro_rules! impl_metric_float {
+    ($($t:ty),+) => {$(
+        impl Metric for $t {
+            fn distance(&self, other: &$t) -> f64 { (*self as f64 - *other as f64).abs() }
+        }
+    )+}
+}
+
+impl_metric_float!(f32, f64);
+
+macro_rules! impl_metric_int {
+    ($($t:ty => $u:ty),+) => {$(
+        impl Metric for $t {
+            fn distance(&self, other: &$t) -> f64 {
+                let (lo, hi) = if self < other { (*self, *other) } else { (*other, *self) };
+
+                // The gap always fits in the unsigned counterpart of the type.
+                (hi as $u).wrapping_sub(lo as $u) as f64
+            }
+        }
+    )+}
+}
+
+impl_metric_int!(
+    i8 => u8, i16 => u16, i32 => u32, i64 => u64, i128 => u128, isize => usize,
+    u8 => u8, u16 => u16, u32 => u32, u64 => u64, u128 => u128, usize => usize
+);
+
+impl Metric for bool {
+    fn distance(&self, other: &bool) -> f64 { if self == other { 0.0 } else { 1.0 } }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_nudge() {
+        assert_eq!(0.0f64.nudge_up(OpenBounds::Epsilon(0.5)), 0.5);
+        assert_eq!(0.0f64.nudge_down(OpenBounds::Epsilon(0.5)), -0.5);
+
+        assert!(1.0f64.nudge_up(OpenBounds::NextRepresentable) > 1.0);
+        assert!(1.0f64.nudge_down(OpenBounds::NextRepresentable) < 1.0);
+
+        assert_eq!(5i32.nudge_up(OpenBounds::Epsilon(0.5)), 6);
+        assert_eq!(5u8.nudge_down(OpenBounds::NextRepresentable), 4);
+    }
+
+    #[test]
+    fn test_nudge_float_bits() {
+        let next = OpenBounds::NextRepresentable;
+
+        assert_eq!(1.0f64.nudge_up(next), 1.0 + f64::EPSILON);
+        assert_eq!(1.0f32.nudge_down(next), 1.0 - f32::EPSILON / 2.0);
+        assert_eq!(0.0f64.nudge_up(next), f64::from_bits(1));
+        assert_eq!((-0.0f64).nudge_down(next), -f64::from_bits(1));
+        assert_eq!((-f64::from_bits(1)).nudge_up(next), 0.0);
+        assert_eq!(f64::MAX.nudge_up(next), f64::INFINITY);
+        assert_eq!(f64::INFINITY.nudge_up(next), f64::INFINITY);
+        assert_eq!(f64::NEG_INFINITY.nudge_up(next), -f64::MAX);
+        assert!(f32::NAN.nudge_down(next).is_nan());
+    }
+
+    #[test]
+    fn test_nudge_saturates() {
+        let next = OpenBounds::NextRepresentable;
+
+        assert_eq!(i8::MAX.nudge_up(next), i8::MAX);
+        assert_eq!(i8::MIN.nudge_down(next), i8::MIN);
+        assert_eq!(0u32.nudge_down(next), 0);
+        assert!(true.nudge_up(next));
+        assert!(!false.nudge_down(next));
+    }
+
+    #[test]
+    fn test_halfway() {
+        assert_eq!(0.0f64.halfway_to(0.1), 0.05);
+        assert_eq!(i8::MIN.halfway_to(i8::MAX), -1);
+        assert_eq!(u8::MAX.halfway_to(0), 127);
+        assert_eq!(3i32.halfway_to(-4), -1);
+    }
+
+    #[test]
+    fn test_metric() {
+        assert_eq!(1.0f64.distance(&-1.0), 2.0);
+        assert_eq!(3u8.distance(&5), 2.0);
+        assert_eq!(true.distance(&false), 1.0);
+        assert_eq!(127i8.distance(&-128), 255.0);
+        assert_eq!(0u64.distance(&u64::MAX), u64::MAX as f64);
+        assert_eq!(i128::MIN.distance(&i128::MAX), u128::MAX as f64);
+    }
+}
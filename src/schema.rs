@@ -0,0 +1,391 @@
+use crate::{
+    dynamic::{DynScalar, DynValue, ScalarKind},
+    intervals::{bounds::{self, OpenOrClosed}, partitions, Interval},
+    ops::{ComplementOf, DecidableEmptiness, DifferencePair, IntersectionPair, UnionPair},
+    Homogeneous,
+    IntervalSet,
+    OrderedSpace,
+    Space,
+};
+use serde_json::{json, Map, Value};
+use std::convert::TryFrom;
+
+/// Trait for spaces whose values can be described by a JSON Schema.
+///
+/// Schemas target the 2020-12 draft of the specification, and describe the
+/// JSON encoding of `Self::Value` used by `serde`.
+///
+/// ```
+/// # extern crate spaces;
+/// # #[macro_use] extern crate serde_json;
+/// # use spaces::{ToJsonSchema, intervals::Interval};
+/// # fn main() {
+/// let space = (Interval::closed_unchecked(0, 5), Interval::lorc_unchecked(0.0, 1.0));
+///
+/// assert_eq!(space.json_schema(), json!({
+///     "type": "array",
+///     "prefixItems": [
+///         {"type": "integer", "minimum": 0, "maximum": 5},
+///         {"type": "number", "exclusiveMinimum": 0.0, "maximum": 1.0},
+///     ],
+///     "items": false,
+///     "minItems": 2,
+///     "maxItems": 2,
+/// }));
+/// # }
+/// ```
+pub trait ToJsonSchema: Space {
+    /// Return a schema matching exactly the (JSON-encoded) elements of the
+    /// space, where this is expressible.
+    fn json_schema(&self) -> Value;
+
+    /// Return the schema as a standalone document, with the `$schema` keyword
+    /// set.
+    fn json_schema_document(&self) -> Value {
+        let mut schema = match self.json_schema() {
+            Value::Object(map) => map,
+            Value::Bool(true) => Map::new(),
+            other => return json!({
+                "$schema": DRAFT,
+                "allOf": [other],
+            }),
+        };
+
+        schema.insert("$schema".to_owned(), Value::from(DRAFT));
+
+        Value::Object(schema)
+    }
+}
+
+const DRAFT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+fn scalar_to_json(val: DynValue) -> Option<Value> {
+    match val {
+        DynValue::Bool(x) => Some(Value::Bool(x)),
+        DynValue::Int(x) => Some(if let Ok(x) = i64::try_from(x) {
+            Value::from(x)
+        } else if let Ok(x) = u64::try_from(x) {
+            Value::from(x)
+        } else {
+            Value::from(x as f64)
+        }),
+        DynValue::Float(x) if x.is_finite() => Some(Value::from(x)),
+        _ => None,
+    }
+}
+
+fn dyn_bound<V: DynScalar>(bound: OpenOrClosed<V>) -> OpenOrClosed<DynValue> {
+    match bound {
+        OpenOrClosed::Open(x) => OpenOrClosed::Open(x.into_dyn()),
+        OpenOrClosed::Closed(x) => OpenOrClosed::Closed(x.into_dyn()),
+    }
+}
+
+/// Returns the schema of the scalars lying between `inf` and `sup`.
+fn scalar_schema<V: DynScalar>(inf: Option<OpenOrClosed<V>>, sup: Option<OpenOrClosed<V>>) -> Value {
+    let inf = inf.map(dyn_bound);
+    let sup = sup.map(dyn_bound);
+
+    let mut schema = Map::new();
+
+    schema.insert("type".to_owned(), Value::from(match V::KIND {
+        ScalarKind::Bool => "boolean",
+        ScalarKind::Int => "integer",
+        ScalarKind::Float | ScalarKind::Float32 => "number",
+    }));
+
+    // Booleans cannot carry numeric bounds, so are enumerated instead.
+    if V::KIND == ScalarKind::Bool {
+        let as_bool = |b: &OpenOrClosed<DynValue>| match b {
+            OpenOrClosed::Open(DynValue::Bool(x)) => OpenOrClosed::Open(*x),
+            OpenOrClosed::Closed(DynValue::Bool(x)) => OpenOrClosed::Closed(*x),
+            _ => unreachable!(),
+        };
+        let values: Vec<Value> = [false, true]
+            .iter()
+            .filter(|&&b| {
+                let above = inf.as_ref().map(as_bool).map_or(true, |l| match l {
+                    OpenOrClosed::Open(l) => b & !l,
+                    OpenOrClosed::Closed(l) => b | !l,
+                });
+                let below = sup.as_ref().map(as_bool).map_or(true, |r| match r {
+                    OpenOrClosed::Open(r) => !b & r,
+                    OpenOrClosed::Closed(r) => !b | r,
+                });
+
+                above && below
+            })
+            .map(|&b| Value::Bool(b))
+            .collect();
+
+        if values.len() < 2 { schema.insert("enum".to_owned(), Value::Array(values)); }
+
+        return Value::Object(schema);
+    }
+
+    let mut bound = |b: Option<OpenOrClosed<DynValue>>, open: &str, closed: &str| {
+        let (key, val) = match b {
+            Some(OpenOrClosed::Open(x)) => (open, x),
+            Some(OpenOrClosed::Closed(x)) => (closed, x),
+            None => return,
+        };
+
+        if let Some(val) = scalar_to_json(val) { schema.insert(key.to_owned(), val); }
+    };
+
+    bound(inf, "exclusiveMinimum", "minimum");
+    bound(sup, "exclusiveMaximum", "maximum");
+
+    Value::Object(schema)
+}
+
+/// Returns the schema of all values of the same JSON type as `schema`.
+fn negate(schema: Value) -> Value {
+    match schema.get("type").cloned() {
+        Some(ty) => json!({ "type": ty, "not": schema }),
+        None => json!({ "not": schema }),
+    }
+}
+
+/// Returns the schema of a fixed-length array with the given item schemas.
+fn array_schema(items: Vec<Value>) -> Value {
+    let n = items.len();
+    let uniform = items.windows(2).all(|w| w[0] == w[1]);
+
+    let mut schema = Map::new();
+
+    schema.insert("type".to_owned(), Value::from("array"));
+
+    if uniform && n > 0 {
+        schema.insert("items".to_owned(), items.into_iter().next().unwrap());
+    } else {
+        schema.insert("prefixItems".to_owned(), Value::Array(items));
+        schema.insert("items".to_owned(), Value::Bool(false));
+    }
+
+    schema.insert("minItems".to_owned(), Value::from(n));
+    schema.insert("maxItems".to_owned(), Value::from(n));
+
+    Value::Object(schema)
+}
+
+///////////////////////////////////////////////////////////////////
+// Scalar Spaces
+///////////////////////////////////////////////////////////////////
+impl<L, R> ToJsonSchema for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: DynScalar,
+{
+    fn json_schema(&self) -> Value {
+        if self.is_empty() { return Value::Bool(false); }
+
+        scalar_schema(self.inf(), self.sup())
+    }
+}
+
+impl<V: DynScalar> ToJsonSchema for IntervalSet<V> {
+    fn json_schema(&self) -> Value {
+        match self.segments() {
+            [] => Value::Bool(false),
+            [s] => s.json_schema(),
+            segments => json!({ "anyOf": segments.iter().map(|s| s.json_schema()).collect::<Vec<_>>() }),
+        }
+    }
+}
+
+impl<V> ToJsonSchema for partitions::Uniform<V> {
+    fn json_schema(&self) -> Value {
+        if self.is_empty() { return Value::Bool(false); }
+
+        scalar_schema(self.inf(), self.sup())
+    }
+}
+
+impl<const N: usize, V: PartialOrd + Clone> ToJsonSchema for partitions::Declarative<N, V> {
+    fn json_schema(&self) -> Value {
+        if self.is_empty() { return Value::Bool(false); }
+
+        scalar_schema(self.inf(), self.sup())
+    }
+}
+
+impl<S: ToJsonSchema> ToJsonSchema for Option<S> {
+    fn json_schema(&self) -> Value { self.as_ref().map_or(Value::Bool(false), |s| s.json_schema()) }
+}
+
+///////////////////////////////////////////////////////////////////
+// Product Spaces
+///////////////////////////////////////////////////////////////////
+impl<const N: usize, D: ToJsonSchema> ToJsonSchema for [D; N] {
+    fn json_schema(&self) -> Value { array_schema(self.iter().map(|d| d.json_schema()).collect()) }
+}
+
+impl<D: ToJsonSchema> ToJsonSchema for Vec<D> {
+    fn json_schema(&self) -> Value { array_schema(self.iter().map(|d| d.json_schema()).collect()) }
+}
+
+impl<D: ToJsonSchema> ToJsonSchema for Homogeneous<D> {
+    fn json_schema(&self) -> Value {
+        json!({
+            "type": "array",
+            "items": self.space.json_schema(),
+            "minItems": self.len,
+            "maxItems": self.len,
+        })
+    }
+}
+
+macro_rules! impl_tuple {
+    ($n:literal; $($tp:ident::$i:tt),+) => {
+        impl<$($tp: ToJsonSchema),+> ToJsonSchema for ($($tp),+) {
+            fn json_schema(&self) -> Value {
+                json!({
+                    "type": "array",
+                    "prefixItems": [$(self.$i.json_schema()),+],
+                    "items": false,
+                    "minItems": $n,
+                    "maxItems": $n,
+                })
+            }
+        }
+    }
+}
+
+impl_tuple!(2; D1::0, D2::1);
+impl_tuple!(3; D1::0, D2::1, D3::2);
+impl_tuple!(4; D1::0, D2::1, D3::2, D4::3);
+impl_tuple!(5; D1::0, D2::1, D3::2, D4::3, D5::4);
+impl_tuple!(6; D1::0, D2::1, D3::2, D4::3, D5::4, D6::5);
+impl_tuple!(7; D1::0, D2::1, D3::2, D4::3, D5::4, D6::5, D7::6);
+impl_tuple!(8; D1::0, D2::1, D3::2, D4::3, D5::4, D6::5, D7::6, D8::7);
+impl_tuple!(9; D1::0, D2::1, D3::2, D4::3, D5::4, D6::5, D7::6, D8::7, D9::8);
+impl_tuple!(10; D1::0, D2::1, D3::2, D4::3, D5::4, D6::5, D7::6, D8::7, D9::8, D10::9);
+impl_tuple!(11; D1::0, D2::1, D3::2, D4::3, D5::4, D6::5, D7::6, D8::7, D9::8, D10::9, D11::10);
+impl_tuple!(12; D1::0, D2::1, D3::2, D4::3, D5::4, D6::5, D7::6, D8::7, D9::8, D10::9, D11::10, D12::11);
+
+///////////////////////////////////////////////////////////////////
+// Set Operations
+///////////////////////////////////////////////////////////////////
+impl<A, B> ToJsonSchema for UnionPair<A, B>
+where
+    A: ToJsonSchema,
+    B: ToJsonSchema<Value = A::Value>,
+{
+    fn json_schema(&self) -> Value { json!({ "anyOf": [self.0.json_schema(), self.1.json_schema()] }) }
+}
+
+impl<A, B> ToJsonSchema for IntersectionPair<A, B>
+where
+    A: ToJsonSchema + DecidableEmptiness<B>,
+    B: ToJsonSchema<Value = A::Value>,
+{
+    fn json_schema(&self) -> Value { json!({ "allOf": [self.0.json_schema(), self.1.json_schema()] }) }
+}
+
+impl<S> ToJsonSchema for ComplementOf<S>
+where
+    S: ToJsonSchema,
+
+    ComplementOf<S>: Space<Value = S::Value>,
+{
+    fn json_schema(&self) -> Value {
+        negate(self.0.json_schema())
+    }
+}
+
+impl<A, B> ToJsonSchema for DifferencePair<A, B>
+where
+    A: ToJsonSchema,
+    B: ToJsonSchema<Value = A::Value>,
+
+    DifferencePair<A, B>: Space<Value = A::Value>,
+{
+    fn json_schema(&self) -> Value {
+        json!({ "allOf": [self.0.json_schema(), negate(self.1.json_schema())] })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{ops::Complement, real::reals};
+
+    #[test]
+    fn test_interval() {
+        assert_eq!(Interval::closed_unchecked(0, 5).json_schema(), json!({
+            "type": "integer", "minimum": 0, "maximum": 5,
+        }));
+        assert_eq!(Interval::open_unchecked(0.0, 1.0).json_schema(), json!({
+            "type": "number", "exclusiveMinimum": 0.0, "exclusiveMaximum": 1.0,
+        }));
+        assert_eq!(reals::<f64>().json_schema(), json!({ "type": "number" }));
+        assert_eq!(Interval::open_unchecked(1.0, 1.0).json_schema(), json!(false));
+
+        assert_eq!(Interval::closed_unchecked(false, true).json_schema(), json!({ "type": "boolean" }));
+        assert_eq!(Interval::degenerate(true).json_schema(), json!({
+            "type": "boolean", "enum": [true],
+        }));
+    }
+
+    #[test]
+    fn test_products() {
+        assert_eq!([Interval::closed_unchecked(0, 1); 3].json_schema(), json!({
+            "type": "array",
+            "items": { "type": "integer", "minimum": 0, "maximum": 1 },
+            "minItems": 3,
+            "maxItems": 3,
+        }));
+        assert_eq!(
+            vec![Interval::closed_unchecked(0, 1), Interval::closed_unchecked(0, 2)].json_schema(),
+            json!({
+                "type": "array",
+                "prefixItems": [
+                    { "type": "integer", "minimum": 0, "maximum": 1 },
+                    { "type": "integer", "minimum": 0, "maximum": 2 },
+                ],
+                "items": false,
+                "minItems": 2,
+                "maxItems": 2,
+            })
+        );
+        assert_eq!(Homogeneous::new(reals::<f64>(), 2).json_schema(), json!({
+            "type": "array",
+            "items": { "type": "number" },
+            "minItems": 2,
+            "maxItems": 2,
+        }));
+    }
+
+    #[test]
+    fn test_set_operations() {
+        let a = Interval::closed_unchecked(0.0, 1.0);
+        let b = Interval::closed_unchecked(2.0, 3.0);
+
+        assert_eq!(UnionPair(a, b).json_schema(), json!({ "anyOf": [
+            { "type": "number", "minimum": 0.0, "maximum": 1.0 },
+            { "type": "number", "minimum": 2.0, "maximum": 3.0 },
+        ]}));
+        assert_eq!(IntervalSet::from(UnionPair(a, b)).json_schema(), UnionPair(a, b).json_schema());
+        assert_eq!([a; 2].complement().json_schema(), json!({
+            "type": "array",
+            "not": ([a; 2].json_schema()),
+        }));
+    }
+
+    #[test]
+    fn test_document() {
+        assert_eq!(partitions::Uniform { size: 4, left: 0.0, right: 1.0 }.json_schema_document(), json!({
+            "$schema": DRAFT,
+            "type": "integer",
+            "minimum": 0,
+            "maximum": 3,
+        }));
+        assert_eq!(None::<Interval<bounds::Closed<f64>, bounds::Closed<f64>>>.json_schema_document(), json!({
+            "$schema": DRAFT,
+            "allOf": [false],
+        }));
+    }
+}
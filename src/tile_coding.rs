@@ -0,0 +1,365 @@
+use crate::{
+    FiniteSpace, Space,
+    intervals::{Interval, bounds},
+    mappings::{self, MappingError},
+};
+use num_traits::{Float, ToPrimitive};
+
+/// Trait for bounded boxes of real intervals over which tilings can be laid.
+pub trait Tileable: Space {
+    /// Return the number of real dimensions of the space.
+    fn n_dims(&self) -> usize;
+
+    /// Return the lower and upper limits of the given dimension.
+    fn extent(&self, dim: usize) -> Result<(f64, f64), MappingError>;
+
+    /// Return the coordinate of `val` along the given dimension.
+    fn coordinate(&self, val: &Self::Value, dim: usize) -> f64;
+}
+
+impl<L, R> Tileable for Interval<L, R>
+where
+    L: bounds::Bound,
+    R: bounds::Bound<Value = L::Value>,
+
+    L::Value: Float,
+{
+    fn n_dims(&self) -> usize { 1 }
+
+    fn extent(&self, _: usize) -> Result<(f64, f64), MappingError> {
+        mappings::limits(self).map(|(l, r)| (l.to_f64().unwrap(), r.to_f64().unwrap()))
+    }
+
+    fn coordinate(&self, val: &L::Value, _: usize) -> f64 { val.to_f64().unwrap() }
+}
+
+impl<const N: usize, D: Tileable> Tileable for [D; N] {
+    fn n_dims(&self) -> usize { self.iter().map(|d| d.n_dims()).sum() }
+
+    fn extent(&self, mut dim: usize) -> Result<(f64, f64), MappingError> {
+        for d in self.iter() {
+            if dim < d.n_dims() { return d.extent(dim); }
+
+            dim -= d.n_dims();
+        }
+
+        unreachable!()
+    }
+
+    fn coordinate(&self, val: &Self::Value, mut dim: usize) -> f64 {
+        for (d, x) in self.iter().zip(val.iter()) {
+            if dim < d.n_dims() { return d.coordinate(x, dim); }
+
+            dim -= d.n_dims();
+        }
+
+        unreachable!()
+    }
+}
+
+macro_rules! impl_tuple {
+    ($($tp:ident::$i:tt),+) => {
+        impl<$($tp: Tileable),+> Tileable for ($($tp),+) {
+            fn n_dims(&self) -> usize { 0 $(+ self.$i.n_dims())+ }
+
+            fn extent(&self, mut dim: usize) -> Result<(f64, f64), MappingError> {
+                $(
+                    if dim < self.$i.n_dims() { return self.$i.extent(dim); }
+
+                    dim -= self.$i.n_dims();
+                )+
+
+                unreachable!()
+            }
+
+            fn coordinate(&self, val: &Self::Value, mut dim: usize) -> f64 {
+                $(
+                    if dim < self.$i.n_dims() { return self.$i.coordinate(&val.$i, dim); }
+
+                    dim -= self.$i.n_dims();
+                )+
+
+                unreachable!()
+            }
+        }
+    }
+}
+
+impl_tuple!(D1::0, D2::1);
+impl_tuple!(D1::0, D2::1, D3::2);
+impl_tuple!(D1::0, D2::1, D3::2, D4::3);
+impl_tuple!(D1::0, D2::1, D3::2, D4::3, D5::4);
+impl_tuple!(D1::0, D2::1, D3::2, D4::3, D5::4, D6::5);
+impl_tuple!(D1::0, D2::1, D3::2, D4::3, D5::4, D6::5, D7::6);
+impl_tuple!(D1::0, D2::1, D3::2, D4::3, D5::4, D6::5, D7::6, D8::7);
+impl_tuple!(D1::0, D2::1, D3::2, D4::3, D5::4, D6::5, D7::6, D8::7, D9::8);
+impl_tuple!(D1::0, D2::1, D3::2, D4::3, D5::4, D6::5, D7::6, D8::7, D9::8, D10::9);
+impl_tuple!(D1::0, D2::1, D3::2, D4::3, D5::4, D6::5, D7::6, D8::7, D9::8, D10::9, D11::10);
+impl_tuple!(D1::0, D2::1, D3::2, D4::3, D5::4, D6::5, D7::6, D8::7, D9::8, D10::9, D11::10, D12::11);
+
+/// Tile coding of a bounded box into overlapping grids of binary features.
+///
+/// Each of the `n_tilings` tilings partitions the box into a grid of tiles,
+/// offset from the others by a fraction of the tile width using the
+/// asymmetric displacements recommended by Sutton & Barto. Every tiling has
+/// one extra tile per dimension to cover its offset, so a value activates
+/// exactly one feature per tiling. Tilings with no offset along a dimension
+/// leave this extra tile unused; the upper limit falls in the last tile.
+///
+/// As a space, a `TileCoding` is the finite set of feature indices. With
+/// hashing enabled, indices are folded into a fixed memory size instead.
+///
+/// ```
+/// # extern crate spaces;
+/// # use spaces::{FiniteSpace, TileCoding, intervals::Interval};
+/// let space = [Interval::closed_unchecked(0.0, 1.0); 2];
+/// let tc = TileCoding::new(space, 4, &[8, 8]).unwrap();
+///
+/// assert_eq!(tc.cardinality(), 4 * 9 * 9);
+/// assert_eq!(tc.active_tiles(&[0.5, 0.5]).len(), 4);
+///
+/// let tc = tc.with_memory(128);
+///
+/// assert_eq!(tc.cardinality(), 128);
+/// assert!(tc.active_tiles(&[0.5, 0.5]).into_iter().all(|i| i < 128));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TileCoding<S> {
+    space: S,
+    n_tilings: usize,
+    tiles: Vec<usize>,
+    lower: Vec<f64>,
+    widths: Vec<f64>,
+    memory: Option<usize>,
+}
+
+impl<S: Tileable> TileCoding<S> {
+    /// Construct a tile coding of `space` with the given number of tilings
+    /// and tiles per dimension.
+    ///
+    /// Returns an error if `space` is unbounded or degenerate along any
+    /// dimension, if `tiles` does not have one entry per dimension, or if
+    /// any count is zero.
+    pub fn new(space: S, n_tilings: usize, tiles: &[usize]) -> Result<TileCoding<S>, MappingError> {
+        let n_dims = space.n_dims();
+
+        if tiles.len() != n_dims {
+            return Err(MappingError::Dimensions { expected: n_dims, found: tiles.len() });
+        }
+
+        if n_tilings == 0 || tiles.contains(&0) { return Err(MappingError::NoBins); }
+
+        let extents = (0..n_dims).map(|d| space.extent(d)).collect::<Result<Vec<_>, _>>()?;
+
+        Ok(TileCoding {
+            lower: extents.iter().map(|e| e.0).collect(),
+            widths: extents.iter().zip(tiles.iter()).map(|(e, &n)| (e.1 - e.0) / n as f64).collect(),
+            tiles: tiles.to_vec(),
+            space,
+            n_tilings,
+            memory: None,
+        })
+    }
+
+    /// Hash feature indices into a table of the given size.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn with_memory(self, size: usize) -> TileCoding<S> {
+        assert!(size > 0, "memory size must be positive");
+
+        TileCoding { memory: Some(size), ..self }
+    }
+
+    /// Return the underlying space.
+    pub fn space(&self) -> &S { &self.space }
+
+    /// Return the number of tilings.
+    pub fn n_tilings(&self) -> usize { self.n_tilings }
+
+    /// Return the number of features in each tiling, before hashing.
+    pub fn tiles_per_tiling(&self) -> usize { self.tiles.iter().map(|n| n + 1).product() }
+
+    /// Write the index of the active feature of each tiling into `out`.
+    ///
+    /// Values beyond the box are assigned to the nearest edge tiles.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of `out` differs from `n_tilings()`.
+    pub fn active_tiles_into(&self, val: &S::Value, out: &mut [usize]) {
+        assert_eq!(out.len(), self.n_tilings, "tile buffer has the wrong length");
+
+        let n = self.n_tilings as f64;
+        let per_tiling = self.tiles_per_tiling();
+
+        for (t, slot) in out.iter_mut().enumerate() {
+            let index = (0..self.tiles.len()).fold(0, |acc, d| {
+                let offset = (t * (2 * d + 1)) % self.n_tilings;
+                let x = (self.space.coordinate(val, d) - self.lower[d]) / self.widths[d]
+                    + offset as f64 / n;
+                let cell = x.floor().max(0.0) as usize;
+                let last = if offset == 0 { self.tiles[d] - 1 } else { self.tiles[d] };
+
+                acc * (self.tiles[d] + 1) + cell.min(last)
+            });
+            let index = t * per_tiling + index;
+
+            *slot = match self.memory {
+                Some(m) => (fnv1a(index as u64) % m as u64) as usize,
+                None => index,
+            };
+        }
+    }
+
+    /// Return the index of the active feature of each tiling.
+    pub fn active_tiles(&self, val: &S::Value) -> Vec<usize> {
+        let mut out = vec![0; self.n_tilings];
+
+        self.active_tiles_into(val, &mut out);
+
+        out
+    }
+}
+
+/// Deterministic 64-bit FNV-1a hash of a feature index.
+fn fnv1a(x: u64) -> u64 {
+    x.to_le_bytes().iter().fold(0xcbf2_9ce4_8422_2325, |h, &b| {
+        (h ^ b as u64).wrapping_mul(0x0000_0100_0000_01b3)
+    })
+}
+
+impl<S: Tileable> Space for TileCoding<S> {
+    type Value = usize;
+
+    fn is_empty(&self) -> bool { false }
+
+    fn contains(&self, val: &usize) -> bool { *val < self.cardinality() }
+}
+
+impl<S: Tileable> FiniteSpace for TileCoding<S> {
+    fn cardinality(&self) -> usize {
+        self.memory.unwrap_or_else(|| self.n_tilings * self.tiles_per_tiling())
+    }
+}
+
+// Tile codings are serialised by their parameters alone. Deserialisation goes
+// through `TileCoding::new` so that the parameters are validated and the tile
+// limits and widths recomputed.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(crate = "serde_crate")]
+struct Params<S, T> {
+    space: S,
+    n_tilings: usize,
+    tiles: T,
+    memory: Option<usize>,
+}
+
+#[cfg(feature = "serde")]
+impl<S: serde_crate::Serialize> serde_crate::Serialize for TileCoding<S> {
+    fn serialize<Z: serde_crate::Serializer>(&self, serializer: Z) -> Result<Z::Ok, Z::Error> {
+        Params {
+            space: &self.space,
+            n_tilings: self.n_tilings,
+            tiles: &self.tiles,
+            memory: self.memory,
+        }.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, S> serde_crate::Deserialize<'de> for TileCoding<S>
+where
+    S: Tileable + serde_crate::Deserialize<'de>,
+{
+    fn deserialize<D: serde_crate::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        use serde_crate::de::Error;
+
+        let params = Params::<S, Vec<usize>>::deserialize(deserializer)?;
+        let tc = TileCoding::new(params.space, params.n_tilings, &params.tiles)
+            .map_err(D::Error::custom)?;
+
+        match params.memory {
+            Some(0) => Err(D::Error::custom("memory size must be positive")),
+            Some(size) => Ok(tc.with_memory(size)),
+            None => Ok(tc),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let space = (Interval::closed_unchecked(0.0f32, 1.0), Interval::closed_unchecked(-1.0, 1.0));
+
+        assert_eq!(
+            TileCoding::new(space, 2, &[4]).unwrap_err(),
+            MappingError::Dimensions { expected: 2, found: 1 }
+        );
+        assert_eq!(TileCoding::new(space, 0, &[4, 4]).unwrap_err(), MappingError::NoBins);
+        assert_eq!(
+            TileCoding::new([Interval::left_closed(0.0)], 2, &[4]).unwrap_err(),
+            MappingError::Unbounded
+        );
+
+        let tc = TileCoding::new(space, 2, &[4, 2]).unwrap();
+
+        assert_eq!(tc.tiles_per_tiling(), 15);
+        assert_eq!(tc.cardinality(), 30);
+    }
+
+    #[test]
+    fn test_active_tiles() {
+        let tc = TileCoding::new(Interval::closed_unchecked(0.0, 4.0), 2, &[4]).unwrap();
+
+        // The second tiling is shifted by half a tile.
+        assert_eq!(tc.active_tiles(&0.0), vec![0, 5]);
+        assert_eq!(tc.active_tiles(&0.6), vec![0, 6]);
+        assert_eq!(tc.active_tiles(&1.2), vec![1, 6]);
+        assert_eq!(tc.active_tiles(&3.999), vec![3, 9]);
+        assert_eq!(tc.active_tiles(&4.0), vec![3, 9]);
+        assert_eq!(tc.active_tiles(&-10.0), vec![0, 5]);
+        assert_eq!(tc.active_tiles(&10.0), vec![3, 9]);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let tc = TileCoding::new([Interval::closed_unchecked(0.0, 1.0); 2], 4, &[8, 8])
+            .unwrap()
+            .with_memory(64);
+        let json = serde_json::to_string(&tc).unwrap();
+        let de: TileCoding<[crate::intervals::Closed<f64>; 2]> = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(json, concat!(
+            r#"{"space":[{"left":0.0,"right":1.0},{"left":0.0,"right":1.0}],"#,
+            r#""n_tilings":4,"tiles":[8,8],"memory":64}"#
+        ));
+        assert_eq!(serde_json::to_string(&de).unwrap(), json);
+        assert_eq!(de.cardinality(), 64);
+        assert_eq!(de.active_tiles(&[0.3, 0.7]), tc.active_tiles(&[0.3, 0.7]));
+
+        type Tc = TileCoding<[crate::intervals::Closed<f64>; 2]>;
+
+        assert!(serde_json::from_str::<Tc>(&json.replace("64", "0")).is_err());
+        assert!(serde_json::from_str::<Tc>(&json.replace("[8,8]", "[8]")).is_err());
+        assert!(serde_json::from_str::<Tc>(&json.replace("\"n_tilings\":4", "\"n_tilings\":0")).is_err());
+    }
+
+    #[test]
+    fn test_memory() {
+        let tc = TileCoding::new([Interval::closed_unchecked(0.0, 1.0); 3], 8, &[10; 3])
+            .unwrap()
+            .with_memory(64);
+        let tiles = tc.active_tiles(&[0.1, 0.5, 0.9]);
+
+        assert_eq!(tiles.len(), 8);
+        assert!(tiles.iter().all(|t| tc.contains(t)));
+        assert_eq!(tiles, tc.active_tiles(&[0.1, 0.5, 0.9]));
+    }
+}
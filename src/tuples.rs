@@ -1,4 +1,101 @@
-use crate::prelude::*;
+use crate::{
+    prelude::*,
+    notation::{self, Notation, Precedence, Style},
+    ops::{DecidableEmptiness, IntersectionOf, SetRelations, UnionPair},
+    project::{Metric, OpenBounds},
+    validate::{ContainmentError, PathSegment},
+};
+use std::{fmt, iter::Map};
+
+/// Lazy Cartesian product of two iterators, in lexicographic order.
+///
+/// The right-hand iterator is cloned afresh for each element of the left, so
+/// no component is ever collected; wider products nest to the right. If the
+/// right-hand iterator is empty then so is the product, and the left is never
+/// advanced.
+#[derive(Clone, Debug)]
+pub struct ProductIter<A: Iterator, B> {
+    a: A,
+    current: Option<A::Item>,
+    b: B,
+    b_orig: B,
+    b_empty: bool,
+}
+
+impl<A: Iterator, B: Iterator + Clone> ProductIter<A, B> {
+    fn new(a: A, b: B) -> ProductIter<A, B> {
+        let b_empty = b.clone().next().is_none();
+
+        ProductIter { a, current: None, b: b.clone(), b_orig: b, b_empty, }
+    }
+}
+
+impl<A, B> Iterator for ProductIter<A, B>
+where
+    A: Iterator,
+    B: Iterator + Clone,
+
+    A::Item: Clone,
+{
+    type Item = (A::Item, B::Item);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.b_empty { return None; }
+
+        loop {
+            if let Some(ref x) = self.current {
+                if let Some(y) = self.b.next() { return Some((x.clone(), y)); }
+            }
+
+            self.current = Some(self.a.next()?);
+            self.b = self.b_orig.clone();
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.b_empty { return (0, Some(0)); }
+
+        let (al, au) = self.a.size_hint();
+        let (ol, ou) = self.b_orig.size_hint();
+        let (bl, bu) = if self.current.is_some() { self.b.size_hint() } else { (0, Some(0)) };
+
+        let lower = al.saturating_mul(ol).saturating_add(bl);
+        let upper = match (au, ou, bu) {
+            (Some(au), Some(ou), Some(bu)) => au.checked_mul(ou).and_then(|n| n.checked_add(bu)),
+            _ => None,
+        };
+
+        (lower, upper)
+    }
+}
+
+impl<A, B> ExactSizeIterator for ProductIter<A, B>
+where
+    A: ExactSizeIterator,
+    B: ExactSizeIterator + Clone,
+
+    A::Item: Clone,
+{}
+
+macro_rules! nested_iter {
+    ($a:ty, $b:ty) => { ProductIter<$a, $b> };
+    ($a:ty, $($rest:ty),+) => { ProductIter<$a, nested_iter!($($rest),+)> };
+}
+
+macro_rules! nested_value {
+    ($a:ty, $b:ty) => { ($a, $b) };
+    ($a:ty, $($rest:ty),+) => { ($a, nested_value!($($rest),+)) };
+}
+
+macro_rules! nested_pat {
+    ($a:ident, $b:ident) => { ($a, $b) };
+    ($a:ident, $($rest:ident),+) => { ($a, nested_pat!($($rest),+)) };
+}
+
+macro_rules! nested_product {
+    ($a:expr, $b:expr) => { ProductIter::new($a, $b) };
+    ($a:expr, $($rest:expr),+) => { ProductIter::new($a, nested_product!($($rest),+)) };
+}
 
 macro_rules! stripped {
     (* $($rest: tt)*) => {
@@ -10,10 +107,13 @@ macro_rules! stripped {
     (&& $($rest: tt)*) => {
         $($rest)*
     };
+    (+ $($rest: tt)*) => {
+        $($rest)*
+    };
 }
 
 macro_rules! impl_tuple {
-    ($n:literal; $(($tp:ident, $vp:ident)::$i:tt),+) => {
+    ($n:literal; $(($tp:ident, $vp:ident, $xp:ident)::$i:tt),+) => {
         impl<$($tp: Space),+> Space for ($($tp),+) {
             type Value = ($($tp::Value),+);
 
@@ -32,36 +132,192 @@ macro_rules! impl_tuple {
             }
         }
 
-        // impl<$($tp: Union),+> Union for ($($tp),+) {
-            // fn union(self, other: &Self) -> Self {
-                // ($(self.$i.union(&other.$i)),+).into()
-            // }
-        // }
+        impl<$($tp: IndexedSpace),+> IndexedSpace for ($($tp),+) {
+            fn index_of(&self, val: &Self::Value) -> Option<usize> {
+                let mut index = 0;
+
+                $(index = index * self.$i.cardinality() + self.$i.index_of(&val.$i)?;)+
+
+                Some(index)
+            }
+
+            fn from_index(&self, index: usize) -> Option<Self::Value> {
+                let mut stride = self.cardinality();
+
+                if index >= stride { return None; }
+
+                Some(($({
+                    let radix = self.$i.cardinality();
+
+                    stride /= radix;
+                    self.$i.from_index(index / stride % radix)?
+                }),+))
+            }
+        }
+
+        impl<$($tp: Encode),+> Encode for ($($tp),+) {
+            fn encoding_len(&self) -> usize {
+                stripped!($(+ self.$i.encoding_len())+)
+            }
+
+            fn encode_into(&self, val: &Self::Value, out: &mut [f32]) {
+                assert_eq!(out.len(), self.encoding_len(), "encoding buffer has the wrong length");
+
+                let mut offset = 0;
+
+                $({
+                    let n = self.$i.encoding_len();
+
+                    offset += n;
+                    self.$i.encode_into(&val.$i, &mut out[(offset - n)..offset]);
+                })+
+            }
+
+            fn decode(&self, features: &[f32]) -> Option<Self::Value> {
+                if features.len() != self.encoding_len() { return None; }
+
+                let mut offset = 0;
+
+                Some(($({
+                    let n = self.$i.encoding_len();
+
+                    offset += n;
+                    self.$i.decode(&features[(offset - n)..offset])?
+                }),+))
+            }
+        }
+
+        impl<$($tp: Measure),+> Measure for ($($tp),+) {
+            fn measure(&self) -> f64 {
+                if self.is_empty() { 0.0 } else { crate::product_measure([$(self.$i.measure()),+]) }
+            }
+        }
+
+        impl<$($tp, $vp),+> DecidableEmptiness<($($vp),+)> for ($($tp),+)
+        where
+            $($tp: DecidableEmptiness<$vp>, $vp: Space<Value = $tp::Value>),+
+        {
+            fn intersection_is_empty(&self, rhs: &($($vp),+)) -> bool {
+                stripped!($(|| self.$i.intersection_is_empty(&rhs.$i))+)
+            }
+        }
+
+        impl<$($tp, $vp),+> SetRelations<($($vp),+)> for ($($tp),+)
+        where
+            $($tp: SetRelations<$vp>, $vp: Space<Value = $tp::Value>),+
+        {
+            fn is_subset_of(&self, rhs: &($($vp),+)) -> bool {
+                self.is_empty() || stripped!($(&& self.$i.is_subset_of(&rhs.$i))+)
+            }
+
+            fn is_superset_of(&self, rhs: &($($vp),+)) -> bool {
+                rhs.is_empty() || stripped!($(&& self.$i.is_superset_of(&rhs.$i))+)
+            }
+
+            fn is_disjoint_from(&self, rhs: &($($vp),+)) -> bool {
+                stripped!($(|| self.$i.is_disjoint_from(&rhs.$i))+)
+            }
+        }
+
+        impl<$($tp: Project),+> Project for ($($tp),+) {
+            fn project_with(&self, val: Self::Value, strategy: OpenBounds) -> Self::Value {
+                ($(self.$i.project_with(val.$i, strategy)),+)
+            }
+        }
+
+        impl<$($tp: Validate),+> Validate for ($($tp),+) {
+            fn validate(&self, val: &Self::Value) -> Result<(), ContainmentError> {
+                $(self.$i.validate(&val.$i).map_err(|e| e.within(PathSegment::Field($i)))?;)+
+
+                Ok(())
+            }
+        }
+
+        impl<$($tp: IterableSpace),+> IterableSpace for ($($tp),+)
+        where
+            $($tp::Value: Clone, $tp::ElemIter: Clone),+
+        {
+            type ElemIter = Map<
+                nested_iter!($($tp::ElemIter),+),
+                fn(nested_value!($($tp::Value),+)) -> Self::Value
+            >;
+
+            fn elements(&self) -> Self::ElemIter {
+                let flatten: fn(nested_value!($($tp::Value),+)) -> Self::Value =
+                    |nested_pat!($($xp),+)| ($($xp),+);
+
+                nested_product!($(self.$i.elements()),+).map(flatten)
+            }
+        }
+
+        impl<$($vp: Metric),+> Metric for ($($vp),+) {
+            fn distance(&self, other: &Self) -> f64 {
+                stripped!($(+ self.$i.distance(&other.$i).powi(2))+).sqrt()
+            }
+        }
+
+        impl<$($tp: Notation),+> Notation for ($($tp),+) {
+            fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+                notation::fmt_tuple(f, style, &[$(&self.$i),+])
+            }
+
+            fn precedence(&self) -> Precedence { Precedence::Product }
+        }
+
+        #[cfg(feature = "rand")]
+        impl<$($tp: Sample),+> Sample for ($($tp),+) {
+            fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Self::Value {
+                ($(self.$i.sample(rng)),+)
+            }
+        }
+
+        impl<$($tp, $vp),+> Intersection<($($vp),+)> for ($($tp),+)
+        where
+            $($tp: Intersection<$vp>, $vp: Space<Value = $tp::Value>),+
+        {
+            type Output = ($(IntersectionOf<$tp, $vp>),+);
+
+            fn intersect(self, rhs: ($($vp),+)) -> Option<Self::Output> {
+                Some(($(self.$i.intersect(rhs.$i)?),+))
+            }
+        }
+
+        impl<S, $($tp: Space),+> Union<S> for ($($tp),+)
+        where
+            S: Space<Value = Self::Value>,
+        {
+            type Output = UnionPair<Self, S>;
 
-        // impl<$($tp: Intersect),+> Intersect for ($($tp),+) {
-            // fn intersect(self, other: &Self) -> Self {
-                // ($(self.$i.intersect(&other.$i)),+).into()
-            // }
-        // }
+            fn union(self, rhs: S) -> Self::Output { UnionPair(self, rhs) }
+        }
+
+        impl<$($tp: Closure),+> Closure for ($($tp),+) {
+            type Output = ($($tp::Output),+);
+
+            fn closure(self) -> Self::Output {
+                ($(self.$i.closure()),+)
+            }
+        }
     }
 }
 
-impl_tuple!(2; (D1, X1)::0, (D2, X2)::1);
-impl_tuple!(3; (D1, X1)::0, (D2, X2)::1, (D3, X3)::2);
-impl_tuple!(4; (D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3);
-impl_tuple!(5; (D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4);
-impl_tuple!(6; (D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5);
-impl_tuple!(7; (D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5, (D7, X7)::6);
-impl_tuple!(8; (D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5, (D7, X7)::6, (D8, X8)::7);
-impl_tuple!(9; (D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5, (D7, X7)::6, (D8, X8)::7, (D9, X9)::8);
-impl_tuple!(10; (D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5, (D7, X7)::6, (D8, X8)::7, (D9, X9)::8, (D10, X10)::9);
-impl_tuple!(11; (D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5, (D7, X7)::6, (D8, X8)::7, (D9, X9)::8, (D10, X10)::9, (D11, X11)::10);
-impl_tuple!(12; (D1, X1)::0, (D2, X2)::1, (D3, X3)::2, (D4, X4)::3, (D5, X5)::4, (D6, X6)::5, (D7, X7)::6, (D8, X8)::7, (D9, X9)::8, (D10, X10)::9, (D11, X11)::10, (D12, X12)::11);
+impl_tuple!(2; (D1, X1, x1)::0, (D2, X2, x2)::1);
+impl_tuple!(3; (D1, X1, x1)::0, (D2, X2, x2)::1, (D3, X3, x3)::2);
+impl_tuple!(4; (D1, X1, x1)::0, (D2, X2, x2)::1, (D3, X3, x3)::2, (D4, X4, x4)::3);
+impl_tuple!(5; (D1, X1, x1)::0, (D2, X2, x2)::1, (D3, X3, x3)::2, (D4, X4, x4)::3, (D5, X5, x5)::4);
+impl_tuple!(6; (D1, X1, x1)::0, (D2, X2, x2)::1, (D3, X3, x3)::2, (D4, X4, x4)::3, (D5, X5, x5)::4, (D6, X6, x6)::5);
+impl_tuple!(7; (D1, X1, x1)::0, (D2, X2, x2)::1, (D3, X3, x3)::2, (D4, X4, x4)::3, (D5, X5, x5)::4, (D6, X6, x6)::5, (D7, X7, x7)::6);
+impl_tuple!(8; (D1, X1, x1)::0, (D2, X2, x2)::1, (D3, X3, x3)::2, (D4, X4, x4)::3, (D5, X5, x5)::4, (D6, X6, x6)::5, (D7, X7, x7)::6, (D8, X8, x8)::7);
+impl_tuple!(9; (D1, X1, x1)::0, (D2, X2, x2)::1, (D3, X3, x3)::2, (D4, X4, x4)::3, (D5, X5, x5)::4, (D6, X6, x6)::5, (D7, X7, x7)::6, (D8, X8, x8)::7, (D9, X9, x9)::8);
+impl_tuple!(10; (D1, X1, x1)::0, (D2, X2, x2)::1, (D3, X3, x3)::2, (D4, X4, x4)::3, (D5, X5, x5)::4, (D6, X6, x6)::5, (D7, X7, x7)::6, (D8, X8, x8)::7, (D9, X9, x9)::8, (D10, X10, x10)::9);
+impl_tuple!(11; (D1, X1, x1)::0, (D2, X2, x2)::1, (D3, X3, x3)::2, (D4, X4, x4)::3, (D5, X5, x5)::4, (D6, X6, x6)::5, (D7, X7, x7)::6, (D8, X8, x8)::7, (D9, X9, x9)::8, (D10, X10, x10)::9, (D11, X11, x11)::10);
+impl_tuple!(12; (D1, X1, x1)::0, (D2, X2, x2)::1, (D3, X3, x3)::2, (D4, X4, x4)::3, (D5, X5, x5)::4, (D6, X6, x6)::5, (D7, X7, x7)::6, (D8, X8, x8)::7, (D9, X9, x9)::8, (D10, X10, x10)::9, (D11, X11, x11)::10, (D12, X12, x12)::11);
 
 #[cfg(test)]
+#[allow(clippy::clone_on_copy)]
 mod tests {
     use super::*;
-    use crate::intervals::Interval;
+    use crate::intervals::{Interval, bounds::OpenOrClosed};
 
     #[test]
     fn test_cardinality() {
@@ -70,4 +326,171 @@ mod tests {
 
         assert_eq!((a, b).cardinality(), 4);
     }
+
+    #[test]
+    fn test_measure() {
+        let a = (Interval::lorc_unchecked(0usize, 2usize), Interval::closed_unchecked(0.0, 0.5));
+
+        assert_eq!(a.measure(), 1.0);
+        assert_eq!((a.0, crate::real::reals::<f64>()).measure(), f64::INFINITY);
+        assert_eq!((Interval::open_unchecked(0, 0), crate::real::reals::<f64>()).measure(), 0.0);
+        assert_eq!((Interval::degenerate(0.0), crate::real::reals::<f64>()).measure(), 0.0);
+    }
+
+    #[test]
+    fn test_intersection_is_empty() {
+        let a = (Interval::closed_unchecked(0, 1), Interval::open_unchecked(0.0, 1.0));
+
+        assert!(!a.intersection_is_empty(&a));
+        assert!(a.intersection_is_empty(&(
+            Interval::closed_unchecked(0, 1),
+            Interval::closed_unchecked(1.0, 2.0)
+        )));
+    }
+
+    #[test]
+    fn test_set_relations() {
+        let a = (Interval::closed_unchecked(0, 1), Interval::open_unchecked(0.0, 1.0));
+        let b = (Interval::closed_unchecked(0, 2), Interval::closed_unchecked(0.0, 1.0));
+
+        assert!(a.is_subset_of(&b));
+        assert!(b.is_superset_of(&a));
+        assert!(!b.is_subset_of(&a));
+        assert!(!a.set_eq(&b));
+        assert!(a.set_eq(&a));
+        assert!(a.is_disjoint_from(&(
+            Interval::closed_unchecked(0, 1),
+            Interval::closed_unchecked(1.0, 2.0)
+        )));
+    }
+
+    #[test]
+    fn test_project() {
+        let space = (Interval::closed_unchecked(0, 1), Interval::open_unchecked(0.0, 1.0));
+        let (x, y) = space.project((5, 0.5));
+
+        assert_eq!(x, 1);
+        assert_eq!(y, 0.5);
+        assert_eq!(space.project_with((-5, 2.0), OpenBounds::Epsilon(0.5)), (0, 0.5));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample() {
+        let mut rng = rand::thread_rng();
+        let space = (Interval::lorc_unchecked(0usize, 2usize), Interval::closed_unchecked(0.0, 1.0));
+
+        for _ in 0..100 {
+            assert!(space.contains(&space.sample(&mut rng)));
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validate::{PathSegment, Side, Violation};
+
+        let s = (
+            Interval::closed_unchecked(0, 1),
+            Interval::closed_unchecked(0.0, 1.0),
+            [Interval::lcro_unchecked(0.0, 1.0); 20],
+        );
+        let mut x = (0, 0.5, [0.0; 20]);
+
+        assert!(s.validate(&x).is_ok());
+
+        x.2[17] = 1.0;
+
+        let err = s.validate(&x).unwrap_err();
+
+        assert_eq!(err.path, vec![PathSegment::Field(2), PathSegment::Index(17)]);
+        assert_eq!(err.violation, Violation::Bound {
+            side: Side::Sup,
+            bound: OpenOrClosed::Open("1.0".to_string()),
+            value: "1.0".to_string(),
+        });
+        assert_eq!(err.to_string(), "at .2[17]: value 1.0 lies above the open upper bound 1.0");
+
+        x.0 = -1;
+
+        assert_eq!(s.validate(&x).unwrap_err().path_string(), ".0");
+    }
+
+    #[test]
+    fn test_indexed() {
+        let s = (crate::discrete::binary(), Interval::lcro_unchecked(0usize, 3), Interval::closed_unchecked(1, 2));
+
+        assert_eq!(s.cardinality(), 12);
+
+        let values: Vec<_> = (0..12).map(|i| s.from_index(i).unwrap()).collect();
+
+        assert_eq!(values[0], (false, 0, 1));
+        assert_eq!(values[1], (false, 0, 2));
+        assert_eq!(values[2], (false, 1, 1));
+        assert_eq!(values[11], (true, 2, 2));
+        assert!(values.iter().enumerate().all(|(i, x)| s.index_of(x) == Some(i)));
+        assert_eq!(s.from_index(12), None);
+        assert_eq!(s.index_of(&(true, 3, 1)), None);
+    }
+
+    #[test]
+    fn test_elements() {
+        let s = (Interval::closed_unchecked(0u8, 1), Interval::lcro_unchecked(0u8, 3));
+
+        assert_eq!(s.elements().len(), 6);
+        assert_eq!(s.elements().next(), Some((0, 0)));
+        assert_eq!(s.elements().last(), Some((1, 2)));
+
+        let s = (Interval::closed_unchecked(0u8, 1), Interval::lcro_unchecked(0u8, 3), Interval::closed_unchecked(5u8, 6));
+        let values: Vec<_> = s.elements().collect();
+
+        assert_eq!(s.elements().len(), 12);
+        assert_eq!(values.len(), 12);
+        assert_eq!(values[0], (0, 0, 5));
+        assert_eq!(values[1], (0, 0, 6));
+        assert_eq!(values[2], (0, 1, 5));
+        assert!(values.iter().enumerate().all(|(i, x)| s.index_of(x) == Some(i)));
+
+        let mut iter = s.elements();
+
+        iter.next();
+        iter.next();
+        iter.next();
+
+        assert_eq!(iter.size_hint(), (9, Some(9)));
+        assert_eq!((Interval::open_unchecked(0, 1), Interval::closed_unchecked(0, 5)).elements().count(), 0);
+        assert_eq!((Interval::closed_unchecked(0, 5), Interval::open_unchecked(0, 1)).elements().count(), 0);
+    }
+
+    #[test]
+    fn test_elements_empty_right() {
+        let s = (Interval::closed_unchecked(0u64, u64::MAX), Interval::open_unchecked(0u8, 1));
+
+        assert_eq!(s.elements().next(), None);
+        assert_eq!(s.elements().size_hint(), (0, Some(0)));
+        assert_eq!(ProductIter::new(0u64.., std::iter::empty::<u8>()).next(), None);
+    }
+
+    #[test]
+    fn test_set_ops() {
+        let a = (Interval::closed_unchecked(0, 4), Interval::open_unchecked(0.0, 1.0));
+        let b = (Interval::closed_unchecked(2, 6), Interval::open_unchecked(0.5, 2.0));
+
+        let i = a.intersect(b).unwrap();
+
+        assert!(i.contains(&(3, 0.75)));
+        assert!(!i.contains(&(1, 0.75)));
+        assert!(!i.contains(&(3, 0.25)));
+        assert!(a.intersect((Interval::closed_unchecked(5, 6), b.1)).is_none());
+
+        let u = a.union(b);
+
+        assert!(u.contains(&(1, 0.25)));
+        assert!(u.contains(&(5, 1.5)));
+        assert!(!u.contains(&(5, 0.25)));
+
+        let c = a.closure();
+
+        assert!(c.contains(&(0, 0.0)));
+        assert!(c.contains(&(4, 1.0)));
+    }
 }
@@ -0,0 +1,208 @@
+//! Module for utilities supporting the validation of values against spaces.
+use intervals::bounds::OpenOrClosed;
+use std::{cmp::Ordering, fmt};
+
+/// A single step from a composite value into one of its components.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum PathSegment {
+    /// Positional field of a tuple, rendered as `.i`.
+    Field(usize),
+
+    /// Element of an array or vector, rendered as `[i]`.
+    Index(usize),
+}
+
+impl fmt::Display for PathSegment {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PathSegment::Field(i) => write!(f, ".{}", i),
+            PathSegment::Index(i) => write!(f, "[{}]", i),
+        }
+    }
+}
+
+/// Which side of a component space a value fell outside of.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Side {
+    /// The infimum of the component.
+    Inf,
+
+    /// The supremum of the component.
+    Sup,
+}
+
+/// The reason a value was rejected by a space.
+#[derive(Clone, PartialEq, Debug)]
+pub enum Violation {
+    /// The value lies beyond one of the bounds of a component.
+    ///
+    /// Both the bound and the value are stored in their `Debug` rendering.
+    Bound {
+        /// The side of the component that was violated.
+        side: Side,
+
+        /// The violated bound, along with its openness.
+        bound: OpenOrClosed<String>,
+
+        /// The offending value.
+        value: String,
+    },
+
+    /// The component is empty, so no value can be contained within it.
+    Empty,
+
+    /// A variable-length value has the wrong number of elements.
+    Length {
+        /// The number of elements required by the space.
+        expected: usize,
+
+        /// The number of elements in the value.
+        found: usize,
+    },
+
+    /// Neither branch of a union contains the value.
+    Union(Box<ContainmentError>, Box<ContainmentError>),
+}
+
+/// Explanation of why a value is not contained in a space.
+///
+/// The `path` locates the offending component relative to the root of the
+/// space, outermost step first; e.g. `.2[17]` is the 18th element of the
+/// third field of a tuple.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ContainmentError {
+    /// Location of the offending component.
+    pub path: Vec<PathSegment>,
+
+    /// Reason the component rejected the value.
+    pub violation: Violation,
+}
+
+impl ContainmentError {
+    /// Construct an error at the root of a space.
+    pub fn new(violation: Violation) -> ContainmentError {
+        ContainmentError { path: vec![], violation, }
+    }
+
+    /// Prefix the path of the error with a step from an enclosing space.
+    pub fn within(mut self, segment: PathSegment) -> ContainmentError {
+        self.path.insert(0, segment);
+        self
+    }
+
+    /// Return the path of the error in its textual form, e.g. `.2[17]`.
+    pub fn path_string(&self) -> String {
+        self.path.iter().map(|s| s.to_string()).collect()
+    }
+}
+
+impl fmt::Display for ContainmentError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if !self.path.is_empty() {
+            write!(f, "at {}: ", self.path_string())?;
+        }
+
+        match &self.violation {
+            Violation::Bound { side, bound, value } => {
+                let (openness, b) = match bound {
+                    OpenOrClosed::Open(b) => ("open", b),
+                    OpenOrClosed::Closed(b) => ("closed", b),
+                };
+                let (side, relation) = match side {
+                    Side::Inf => ("lower", "below"),
+                    Side::Sup => ("upper", "above"),
+                };
+
+                write!(f, "value {} lies {} the {} {} bound {}", value, relation, openness, side, b)
+            },
+            Violation::Empty => f.write_str("space is empty"),
+            Violation::Length { expected, found } => {
+                write!(f, "expected {} elements, found {}", expected, found)
+            },
+            Violation::Union(a, b) => {
+                write!(f, "no branch of the union contains the value ({}; {})", a, b)
+            },
+        }
+    }
+}
+
+impl std::error::Error for ContainmentError {}
+
+/// Check `val` against an optional infimum and supremum.
+pub(crate) fn check_bounds<V: PartialOrd + fmt::Debug>(
+    inf: Option<OpenOrClosed<V>>,
+    sup: Option<OpenOrClosed<V>>,
+    val: &V,
+) -> Result<(), ContainmentError> {
+    use self::OpenOrClosed::*;
+
+    let violated = |side, bound: OpenOrClosed<V>| ContainmentError::new(Violation::Bound {
+        side,
+        bound: match bound {
+            Open(b) => Open(format!("{:?}", b)),
+            Closed(b) => Closed(format!("{:?}", b)),
+        },
+        value: format!("{:?}", val),
+    });
+
+    let below = match inf {
+        Some(Open(ref l)) => val.partial_cmp(l) != Some(Ordering::Greater),
+        Some(Closed(ref l)) => val.partial_cmp(l).map_or(true, |o| o == Ordering::Less),
+        None => false,
+    };
+
+    if below { return Err(violated(Side::Inf, inf.unwrap())); }
+
+    let above = match sup {
+        Some(Open(ref r)) => val.partial_cmp(r) != Some(Ordering::Less),
+        Some(Closed(ref r)) => val.partial_cmp(r).map_or(true, |o| o == Ordering::Greater),
+        None => false,
+    };
+
+    if above { Err(violated(Side::Sup, sup.unwrap())) } else { Ok(()) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let err = ContainmentError::new(Violation::Bound {
+            side: Side::Sup,
+            bound: OpenOrClosed::Open("1.0".to_string()),
+            value: "1.5".to_string(),
+        }).within(PathSegment::Index(17)).within(PathSegment::Field(2));
+
+        assert_eq!(err.path_string(), ".2[17]");
+        assert_eq!(err.to_string(), "at .2[17]: value 1.5 lies above the open upper bound 1.0");
+
+        let err = ContainmentError::new(Violation::Empty);
+
+        assert_eq!(err.to_string(), "space is empty");
+    }
+
+    #[test]
+    fn test_check_bounds() {
+        use self::OpenOrClosed::*;
+
+        assert!(check_bounds(Some(Closed(0)), Some(Open(5)), &0).is_ok());
+        assert!(check_bounds(None, None, &0).is_ok());
+
+        let err = check_bounds(Some(Open(0)), None, &0).unwrap_err();
+
+        assert_eq!(err.violation, Violation::Bound {
+            side: Side::Inf,
+            bound: Open("0".to_string()),
+            value: "0".to_string(),
+        });
+
+        let err = check_bounds(Some(Closed(0)), Some(Closed(5)), &6).unwrap_err();
+
+        assert_eq!(err.violation, Violation::Bound {
+            side: Side::Sup,
+            bound: Closed("5".to_string()),
+            value: "6".to_string(),
+        });
+    }
+}
@@ -0,0 +1,488 @@
+use crate::{
+    prelude::*,
+    notation::{self, Notation, Precedence, Style},
+    ops::{DecidableEmptiness, IntersectionOf, UnionPair},
+    project::{Metric, OpenBounds},
+    validate::{ContainmentError, PathSegment, Violation},
+};
+use itertools::{Either, Itertools, structs::MultiProduct};
+use std::{convert::TryFrom, fmt, iter};
+
+///////////////////////////////////////////////////////////////////
+// Vec<D>
+///////////////////////////////////////////////////////////////////
+impl<D: Space> Space for Vec<D> {
+    type Value = Vec<D::Value>;
+
+    fn is_empty(&self) -> bool { self.iter().any(|d| d.is_empty()) }
+
+    fn contains(&self, val: &Self::Value) -> bool {
+        self.len() == val.len() && self.iter().zip(val.iter()).all(|(d, x)| d.contains(x))
+    }
+}
+
+impl<D: FiniteSpace> FiniteSpace for Vec<D> {
+    /// # Panics
+    ///
+    /// Panics if the number of elements overflows a `usize`.
+    fn cardinality(&self) -> usize {
+        self.iter()
+            .try_fold(1usize, |acc, d| acc.checked_mul(d.cardinality()))
+            .expect("cardinality overflows usize")
+    }
+}
+
+impl<D: Measure> Measure for Vec<D> {
+    fn measure(&self) -> f64 {
+        if Space::is_empty(self) { 0.0 } else { crate::product_measure(self.iter().map(|d| d.measure())) }
+    }
+}
+
+impl<D: IterableSpace> IterableSpace for Vec<D>
+where
+    D::Value: Clone,
+    D::ElemIter: Clone,
+{
+    type ElemIter = Either<MultiProduct<D::ElemIter>, iter::Once<Vec<D::Value>>>;
+
+    fn elements(&self) -> Self::ElemIter {
+        // The empty product has exactly one element, which MultiProduct omits.
+        if self.is_empty() { return Either::Right(iter::once(vec![])); }
+
+        Either::Left(self.iter().map(|s| s.elements()).multi_cartesian_product())
+    }
+}
+
+impl<D: Project> Project for Vec<D> {
+    /// # Panics
+    ///
+    /// Panics if `val` has a different length to the space.
+    fn project_with(&self, val: Self::Value, strategy: OpenBounds) -> Self::Value {
+        assert_eq!(self.len(), val.len(), "value has the wrong number of elements");
+
+        self.iter().zip(val).map(|(d, x)| d.project_with(x, strategy)).collect()
+    }
+}
+
+impl<D: Validate> Validate for Vec<D> {
+    fn validate(&self, val: &Self::Value) -> Result<(), ContainmentError> {
+        if self.len() != val.len() {
+            return Err(ContainmentError::new(Violation::Length {
+                expected: self.len(),
+                found: val.len(),
+            }));
+        }
+
+        self.iter().zip(val.iter()).enumerate().try_for_each(|(i, (d, x))| {
+            d.validate(x).map_err(|e| e.within(PathSegment::Index(i)))
+        })
+    }
+}
+
+impl<V: Metric> Metric for Vec<V> {
+    fn distance(&self, other: &Vec<V>) -> f64 {
+        self.iter().zip(other.iter()).map(|(x, y)| x.distance(y).powi(2)).sum::<f64>().sqrt()
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<D: Sample> Sample for Vec<D> {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Self::Value {
+        self.iter().map(|d| d.sample(rng)).collect()
+    }
+}
+
+impl<D, E> DecidableEmptiness<Vec<E>> for Vec<D>
+where
+    D: DecidableEmptiness<E>,
+    E: Space<Value = D::Value>,
+{
+    fn intersection_is_empty(&self, rhs: &Vec<E>) -> bool {
+        self.len() != rhs.len() ||
+            self.iter().zip(rhs.iter()).any(|(d, e)| d.intersection_is_empty(e))
+    }
+}
+
+impl<D, S> Union<S> for Vec<D>
+where
+    D: Space,
+    S: Space<Value = Vec<D::Value>>,
+{
+    type Output = UnionPair<Self, S>;
+
+    fn union(self, rhs: S) -> Self::Output { UnionPair(self, rhs) }
+}
+
+impl<D, E> Intersection<Vec<E>> for Vec<D>
+where
+    D: Intersection<E>,
+    E: Space<Value = D::Value>,
+{
+    type Output = Vec<D::Output>;
+
+    fn intersect(self, rhs: Vec<E>) -> Option<Self::Output> {
+        if self.len() != rhs.len() { return None; }
+
+        self.into_iter().zip(rhs).map(|(d, e)| d.intersect(e)).collect()
+    }
+}
+
+impl<D: Closure> Closure for Vec<D> {
+    type Output = Vec<D::Output>;
+
+    fn closure(self) -> Self::Output { self.into_iter().map(|d| d.closure()).collect() }
+}
+
+impl<D: Notation> Notation for Vec<D> {
+    fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+        notation::fmt_product(f, style, self)
+    }
+
+    fn precedence(&self) -> Precedence { notation::product_precedence(self) }
+}
+
+///////////////////////////////////////////////////////////////////
+// Homogeneous<D>
+///////////////////////////////////////////////////////////////////
+/// Type representing the `len`-fold product of a single space.
+///
+/// This is equivalent to `vec![space; len]`, but stores only one copy of the
+/// sub-space.
+///
+/// ```
+/// # extern crate spaces;
+/// # use spaces::{Homogeneous, Space, intervals::Interval};
+/// let space = Homogeneous::new(Interval::closed_unchecked(0.0, 1.0), 1000);
+///
+/// assert!(space.contains(&vec![0.5; 1000]));
+/// assert!(!space.contains(&vec![0.5; 999]));
+/// ```
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(Serialize, Deserialize),
+    serde(crate = "serde_crate")
+)]
+pub struct Homogeneous<D> {
+    /// The sub-space shared by every dimension.
+    pub space: D,
+
+    /// The number of dimensions.
+    pub len: usize,
+}
+
+impl<D> Homogeneous<D> {
+    /// Construct the `len`-fold product of `space`.
+    pub fn new(space: D, len: usize) -> Homogeneous<D> { Homogeneous { space, len } }
+}
+
+impl<D: Space> Space for Homogeneous<D> {
+    type Value = Vec<D::Value>;
+
+    fn is_empty(&self) -> bool { self.len > 0 && self.space.is_empty() }
+
+    fn contains(&self, val: &Self::Value) -> bool {
+        self.len == val.len() && val.iter().all(|x| self.space.contains(x))
+    }
+}
+
+impl<D: FiniteSpace> FiniteSpace for Homogeneous<D> {
+    /// # Panics
+    ///
+    /// Panics if the number of elements overflows a `usize`.
+    fn cardinality(&self) -> usize {
+        u32::try_from(self.len).ok()
+            .and_then(|len| self.space.cardinality().checked_pow(len))
+            .expect("cardinality overflows usize")
+    }
+}
+
+impl<D: Measure> Measure for Homogeneous<D> {
+    fn measure(&self) -> f64 {
+        if self.is_empty() { 0.0 } else { crate::product_measure(iter::repeat(self.space.measure()).take(self.len)) }
+    }
+}
+
+impl<D: IterableSpace> IterableSpace for Homogeneous<D>
+where
+    D::Value: Clone,
+    D::ElemIter: Clone,
+{
+    type ElemIter = Either<MultiProduct<D::ElemIter>, iter::Once<Vec<D::Value>>>;
+
+    fn elements(&self) -> Self::ElemIter {
+        // The empty product has exactly one element, which MultiProduct omits.
+        if self.len == 0 { return Either::Right(iter::once(vec![])); }
+
+        Either::Left((0..self.len).map(|_| self.space.elements()).multi_cartesian_product())
+    }
+}
+
+impl<D: Project> Project for Homogeneous<D> {
+    /// # Panics
+    ///
+    /// Panics if `val` has a different length to the space.
+    fn project_with(&self, val: Self::Value, strategy: OpenBounds) -> Self::Value {
+        assert_eq!(self.len, val.len(), "value has the wrong number of elements");
+
+        val.into_iter().map(|x| self.space.project_with(x, strategy)).collect()
+    }
+}
+
+impl<D: Validate> Validate for Homogeneous<D> {
+    fn validate(&self, val: &Self::Value) -> Result<(), ContainmentError> {
+        if self.len != val.len() {
+            return Err(ContainmentError::new(Violation::Length {
+                expected: self.len,
+                found: val.len(),
+            }));
+        }
+
+        val.iter().enumerate().try_for_each(|(i, x)| {
+            self.space.validate(x).map_err(|e| e.within(PathSegment::Index(i)))
+        })
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<D: Sample> Sample for Homogeneous<D> {
+    fn sample<R: rand::Rng + ?Sized>(&self, rng: &mut R) -> Self::Value {
+        (0..self.len).map(|_| self.space.sample(rng)).collect()
+    }
+}
+
+impl<D, E> DecidableEmptiness<Homogeneous<E>> for Homogeneous<D>
+where
+    D: DecidableEmptiness<E>,
+    E: Space<Value = D::Value>,
+{
+    fn intersection_is_empty(&self, rhs: &Homogeneous<E>) -> bool {
+        self.len != rhs.len || (self.len > 0 && self.space.intersection_is_empty(&rhs.space))
+    }
+}
+
+impl<D, S> Union<S> for Homogeneous<D>
+where
+    D: Space,
+    S: Space<Value = Vec<D::Value>>,
+{
+    type Output = UnionPair<Self, S>;
+
+    fn union(self, rhs: S) -> Self::Output { UnionPair(self, rhs) }
+}
+
+impl<D, E> Intersection<Homogeneous<E>> for Homogeneous<D>
+where
+    D: Intersection<E>,
+    E: Space<Value = D::Value>,
+{
+    type Output = Homogeneous<IntersectionOf<D, E>>;
+
+    fn intersect(self, rhs: Homogeneous<E>) -> Option<Self::Output> {
+        if self.len != rhs.len { return None; }
+
+        let len = self.len;
+
+        self.space.intersect(rhs.space).map(|space| Homogeneous { space, len })
+    }
+}
+
+impl<D: Closure> Closure for Homogeneous<D> {
+    type Output = Homogeneous<D::Output>;
+
+    fn closure(self) -> Self::Output {
+        let len = self.len;
+
+        Homogeneous { space: self.space.closure(), len }
+    }
+}
+
+impl<D: Notation> Notation for Homogeneous<D> {
+    fn fmt_notation(&self, f: &mut fmt::Formatter<'_>, style: Style) -> fmt::Result {
+        match self.len {
+            0 => notation::fmt_empty_product(f, style),
+            n => notation::fmt_power(f, style, &self.space, n),
+        }
+    }
+
+    fn precedence(&self) -> Precedence {
+        if self.len == 0 { Precedence::Atom } else { Precedence::Power }
+    }
+}
+
+impl<D: Notation> fmt::Display for Homogeneous<D> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { self.fmt_notation(f, Style::Unicode) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::intervals::{Interval, bounds};
+
+    #[test]
+    fn test_vec_contains() {
+        let s = vec![Interval::degenerate(0.0f64), Interval::unit()];
+
+        assert!(s.contains(&vec![0.0, 0.5]));
+        assert!(!s.contains(&vec![1.0, 0.5]));
+        assert!(!s.contains(&vec![0.0]));
+        assert!(!s.contains(&vec![0.0, 0.5, 0.5]));
+    }
+
+    #[test]
+    fn test_vec_is_empty() {
+        let s = vec![Interval::open_unchecked(0.0f64, 0.0), Interval::open_unchecked(1.0, 5.0)];
+
+        assert!(Space::is_empty(&s));
+        assert_eq!(s.measure(), 0.0);
+        assert!(!Space::is_empty(&Vec::<crate::real::Reals<f64>>::new()));
+        assert_eq!(vec![Interval::closed_unchecked(0.0, 2.0); 3].measure(), 8.0);
+        assert_eq!(vec![
+            Interval::closed_unchecked(0.0, f64::INFINITY),
+            Interval::degenerate(0.0),
+        ].measure(), 0.0);
+    }
+
+    #[test]
+    fn test_vec_elements() {
+        let s = vec![Interval::closed_unchecked(0, 1), Interval::closed_unchecked(2, 3)];
+
+        assert_eq!(s.cardinality(), 4);
+        assert_eq!(s.elements().collect::<Vec<_>>(), vec![
+            vec![0, 2], vec![0, 3], vec![1, 2], vec![1, 3],
+        ]);
+
+        let e: Vec<Interval<bounds::Closed<i32>, bounds::Closed<i32>>> = vec![];
+
+        assert_eq!(e.cardinality(), 1);
+        assert_eq!(e.elements().collect::<Vec<_>>(), vec![Vec::<i32>::new()]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_vec_project_wrong_length() {
+        vec![Interval::closed_unchecked(0.0, 1.0); 2].project(vec![0.5]);
+    }
+
+    #[test]
+    fn test_vec_intersection() {
+        let s = vec![Interval::closed_unchecked(0.0, 2.0); 2];
+        let i = s.clone().intersect(vec![
+            Interval::closed_unchecked(1.0, 3.0),
+            Interval::closed_unchecked(-1.0, 1.0),
+        ]).unwrap();
+
+        assert_eq!(i, vec![Interval::closed_unchecked(1.0, 2.0), Interval::closed_unchecked(0.0, 1.0)]);
+        assert!(s.clone().intersect(vec![Interval::closed_unchecked(0.0, 1.0)]).is_none());
+        assert!(s.intersect(vec![Interval::closed_unchecked(3.0, 4.0); 2]).is_none());
+    }
+
+    #[test]
+    fn test_vec_union() {
+        let u = vec![Interval::closed_unchecked(0, 1); 2].union(vec![Interval::degenerate(5); 2]);
+
+        assert!(u.contains(&vec![0, 1]));
+        assert!(u.contains(&vec![5, 5]));
+        assert!(!u.contains(&vec![0, 5]));
+    }
+
+    #[test]
+    fn test_vec_closure() {
+        let s = vec![Interval::open_unchecked(0.0, 1.0); 3].closure();
+
+        assert!(s.contains(&vec![0.0, 1.0, 0.5]));
+    }
+
+    #[test]
+    fn test_homogeneous() {
+        let s = Homogeneous::new(Interval::closed_unchecked(0, 2), 3);
+
+        assert!(!s.is_empty());
+        assert!(s.contains(&vec![0, 1, 2]));
+        assert!(!s.contains(&vec![0, 1, 3]));
+        assert!(!s.contains(&vec![0, 1]));
+
+        assert_eq!(s.cardinality(), 27);
+        assert_eq!(s.measure(), 27.0);
+        assert_eq!(s.elements().count(), 27);
+        assert!(s.elements().all(|x| s.contains(&x)));
+    }
+
+    #[test]
+    fn test_homogeneous_cardinality() {
+        let s = Homogeneous::new(Interval::closed_unchecked(0, 1), 0);
+
+        assert_eq!(s.cardinality(), 1);
+        assert_eq!(s.elements().collect::<Vec<_>>(), vec![Vec::<i32>::new()]);
+        assert_eq!(Homogeneous::new(Interval::closed_unchecked(0, 1), 10).elements().count(), 1024);
+    }
+
+    #[test]
+    #[should_panic(expected = "cardinality overflows usize")]
+    fn test_homogeneous_cardinality_overflow() {
+        Homogeneous::new(Interval::closed_unchecked(0, 1), 1000).cardinality();
+    }
+
+    #[test]
+    fn test_homogeneous_is_empty() {
+        assert!(Homogeneous::new(Interval::open_unchecked(0.0, 0.0), 2).is_empty());
+        assert!(!Homogeneous::new(Interval::open_unchecked(0.0, 0.0), 0).is_empty());
+    }
+
+    #[test]
+    fn test_homogeneous_ops() {
+        let s = Homogeneous::new(Interval::closed_unchecked(0.0, 2.0), 2);
+        let t = Homogeneous::new(Interval::closed_unchecked(1.0, 3.0), 2);
+
+        assert_eq!(s.intersect(t).unwrap(), Homogeneous::new(Interval::closed_unchecked(1.0, 2.0), 2));
+        assert!(s.intersect(Homogeneous::new(Interval::closed_unchecked(1.0, 3.0), 3)).is_none());
+        assert!(s.intersection_is_empty(&Homogeneous::new(Interval::closed_unchecked(3.0, 4.0), 2)));
+
+        let c = Homogeneous::new(Interval::open_unchecked(0.0, 1.0), 2).closure();
+
+        assert!(c.contains(&vec![0.0, 1.0]));
+        assert_eq!(s.project(vec![-1.0, 5.0]), vec![0.0, 2.0]);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_sample() {
+        let mut rng = rand::thread_rng();
+        let s = Homogeneous::new(Interval::closed_unchecked(0.0, 1.0), 10);
+        let v = vec![Interval::closed_unchecked(0.0, 1.0); 10];
+
+        for _ in 0..100 {
+            assert!(s.contains(&s.sample(&mut rng)));
+            assert!(v.contains(&v.sample(&mut rng)));
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let s = Homogeneous::new(Interval::closed_unchecked(0, 2), 3);
+        let json = serde_json::to_string(&s).unwrap();
+
+        assert_eq!(json, r#"{"space":{"left":0,"right":2},"len":3}"#);
+        assert_eq!(serde_json::from_str::<Homogeneous<_>>(&json).unwrap(), s);
+    }
+
+    #[test]
+    fn test_validate() {
+        use crate::validate::{PathSegment, Violation};
+
+        let s = vec![Interval::degenerate(0.0f64), Interval::unit()];
+
+        assert!(s.validate(&vec![0.0, 0.5]).is_ok());
+        assert_eq!(s.validate(&vec![0.0, 1.5]).unwrap_err().path, vec![PathSegment::Index(1)]);
+        assert_eq!(
+            s.validate(&vec![0.0]).unwrap_err().violation,
+            Violation::Length { expected: 2, found: 1 }
+        );
+
+        let s = Homogeneous::new(Interval::unit(), 3);
+
+        assert!(s.validate(&vec![0.0, 0.5, 1.0]).is_ok());
+        assert_eq!(s.validate(&vec![0.0, 0.5, 2.0]).unwrap_err().path_string(), "[2]");
+    }
+}